@@ -93,6 +93,16 @@ pub struct EnergyCharging;
 #[derive(Component)]
 pub struct InRepulsionField;
 
+/// Mid-dash: no steering or gravity, and no pushing against other colliders.
+#[derive(Component)]
+pub struct Dashing;
+
+/// Ignores all incoming damage until the timer finishes.
+#[derive(Component)]
+pub struct Invulnerable {
+	pub timer: Timer,
+}
+
 // ============ Target Tags ============
 
 #[derive(Component)]
@@ -101,6 +111,10 @@ pub struct PlayerTag;
 #[derive(Component)]
 pub struct EnemyTag;
 
+/// Player-side creature (e.g. a summoned minion). Takes damage aimed at the player.
+#[derive(Component)]
+pub struct AllyTag;
+
 #[derive(Component)]
 pub struct ProjectileTag;
 
@@ -131,6 +145,7 @@ pub struct ProjectileSpawner {
 	pub spawn_logic: SpawnLogic,
 	pub fire_range: Option<f32>, // None = infinite range
 	pub energy_cost: f32,
+	pub spiral_angle: f32, // Current rotation of Spiral spawn logic, seeded randomly per spawner
 }
 
 #[derive(Clone)]
@@ -140,6 +155,49 @@ pub struct ProjectileTemplate {
 	pub lifetime: f32,
 	pub size: (f32, f32),
 	pub color: (f32, f32, f32),
+	pub motion: ProjectileMotion,
+	pub platform_collision: PlatformCollision,
+}
+
+/// What a projectile does when it hits a Ground platform.
+#[derive(Clone, Copy, Default, Deserialize)]
+pub enum PlatformCollision {
+	#[default]
+	Destroy,
+	Bounce {
+		max_bounces: u32, // Destroyed on the hit after the last bounce
+	},
+	PassThrough,
+}
+
+#[derive(Component)]
+pub struct ProjectilePlatformCollision {
+	pub mode: PlatformCollision,
+	pub bounces: u32,
+}
+
+#[derive(Clone, Copy, Default, Deserialize)]
+pub enum ProjectileMotion {
+	#[default]
+	Straight,
+	Boomerang {
+		max_distance: f32,
+		cooldown_refund: f32, // Seconds taken off the spawner's cooldown when caught
+	},
+}
+
+/// Projectile that flies out, decelerates to a stop at its max distance and returns to its owner.
+/// Hits each target at most once per leg.
+#[derive(Component)]
+pub struct BoomerangProjectile {
+	pub owner: Entity,
+	pub damage: f32,
+	pub targets: TargetFilter,
+	pub speed: f32,
+	pub deceleration: f32,
+	pub returning: bool,
+	pub cooldown_refund: f32,
+	pub hit_entities: Vec<Entity>, // Cleared when the projectile turns around
 }
 
 #[derive(Clone, Deserialize)]
@@ -147,6 +205,68 @@ pub enum SpawnLogic {
 	NearestEnemy,
 	PlayerDirection,
 	Fixed(f32, f32),
+	Radial { count: u32 }, // Ring of projectiles in every direction
+	Spiral { count: u32, rotation_speed: f32 }, // Ring that rotates over time (radians/sec)
+	AimedSpread { count: u32, angle: f32 }, // Fan aimed at the target, angle = total spread in degrees
+}
+
+// ============ Beam Behaviors ============
+
+#[derive(Component)]
+pub struct BeamEmitter {
+	pub damage_per_second: f32,
+	pub range: f32,
+	pub width: f32,
+	pub color: (f32, f32, f32),
+	pub energy_per_second: f32,
+	pub piercing: bool,         // true = passes through Ground colliders
+	pub visual: Option<Entity>, // Stretched sprite shown while the beam is firing
+}
+
+/// Marker for the stretched sprite that renders an active beam.
+#[derive(Component)]
+pub struct BeamVisual;
+
+// ============ Deployable Behaviors ============
+
+/// Drops a stationary entity at the player's position every cooldown.
+/// The deployed entity gets its own nested behaviors (turret spawner, mine explosion, ...).
+#[derive(Component)]
+pub struct Deployer {
+	pub cooldown: Timer,
+	pub lifetime: f32,
+	pub max_active: u32, // Oldest deployment is removed when a new one would exceed this
+	pub size: (f32, f32),
+	pub color: (f32, f32, f32),
+	pub energy_cost: f32,
+	pub behaviors: Vec<BehaviorData>,
+}
+
+/// Entity placed by a Deployer. Treated as player-owned by targeting systems.
+#[derive(Component)]
+pub struct Deployed {
+	pub owner: Entity,
+}
+
+// ============ Summon Behaviors ============
+
+/// Summons allied minions at the player's position every cooldown, up to max_summons alive.
+#[derive(Component)]
+pub struct Summoner {
+	pub cooldown: Timer,
+	pub max_summons: u32,
+	pub health: f32,
+	pub lifetime: f32,
+	pub size: (f32, f32),
+	pub color: (f32, f32, f32),
+	pub energy_cost: f32,
+	pub damage: f32, // Overrides the minion's DamageOnContact damage so upgrades apply
+	pub behaviors: Vec<BehaviorData>,
+}
+
+#[derive(Component)]
+pub struct Summoned {
+	pub owner: Entity,
 }
 
 // ============ Melee Behaviors ============
@@ -173,14 +293,46 @@ pub struct MeleeAttackState {
 	pub hitbox_size: (f32, f32),
 	pub hitbox_color: (f32, f32, f32),
 	pub attack_direction: Vec2,
+	pub finisher: Option<ComboFinisher>,
 }
 
 #[derive(Component)]
 pub struct MeleeHitbox {
+	pub owner: Entity, // Player performing the attack
 	pub damage: f32,
 	pub stun_duration: f32,
 	pub knockback_force: f32,
 	pub hit_entities: Vec<Entity>,
+	pub finisher: Option<ComboFinisher>,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+pub enum ComboFinisher {
+	Launcher { launch_force: f32 }, // Knocks enemies upward
+	GroundSlam { slam_speed: f32 }, // Player drives downward, enemies are knocked along the ground
+}
+
+#[derive(Clone, Deserialize)]
+pub struct ComboHitData {
+	pub damage_multiplier: f32,
+	pub knockback_multiplier: f32,
+	pub hitbox_size: (f32, f32),
+	#[serde(default)]
+	pub finisher: Option<ComboFinisher>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct MeleeComboData {
+	pub window: f32, // Seconds after an attack in which the next attack continues the combo
+	pub hits: Vec<ComboHitData>,
+}
+
+/// Tracks progress through a melee weapon's combo chain.
+#[derive(Component)]
+pub struct MeleeCombo {
+	pub hits: Vec<ComboHitData>,
+	pub window: Timer,
+	pub step: usize, // Index of the next hit in the chain
 }
 
 #[derive(Component)]
@@ -211,6 +363,39 @@ pub enum BehaviorData {
 		spawn_logic: SpawnLogic,
 		fire_range: Option<f32>,
 		energy_cost: f32,
+		#[serde(default)]
+		motion: ProjectileMotion,
+		#[serde(default)]
+		platform_collision: PlatformCollision,
+	},
+	Beam {
+		damage_per_second: f32,
+		range: f32,
+		width: f32,
+		color: (f32, f32, f32),
+		energy_per_second: f32,
+		piercing: bool,
+	},
+	Deployable {
+		cooldown: f32,
+		lifetime: f32,
+		max_active: u32,
+		size: (f32, f32),
+		color: (f32, f32, f32),
+		#[serde(default)]
+		energy_cost: f32,
+		behaviors: Vec<BehaviorData>,
+	},
+	Summon {
+		cooldown: f32,
+		max_summons: u32,
+		health: f32,
+		lifetime: f32,
+		size: (f32, f32),
+		color: (f32, f32, f32),
+		#[serde(default)]
+		energy_cost: f32,
+		behaviors: Vec<BehaviorData>,
 	},
 	MeleeAttack {
 		cooldown: f32,
@@ -222,6 +407,8 @@ pub enum BehaviorData {
 		hitbox_size: (f32, f32),
 		hitbox_color: (f32, f32, f32),
 		energy_cost: f32,
+		#[serde(default)]
+		combo: Option<MeleeComboData>,
 	},
 	FollowPlayer,
 	SeekTarget {
@@ -262,11 +449,13 @@ pub struct WeaponId(pub String);
 #[derive(Component, Clone, Copy)]
 pub struct WeaponLevel(pub u32);
 
+/// Name of the configured weapon slot (see WeaponSlotConfig) that activates this weapon.
+#[derive(Component, Clone, PartialEq, Eq, Debug)]
+pub struct WeaponSlot(pub String);
+
+/// Player a weapon belongs to. Deployed and summoned entities inherit their weapon's owner.
 #[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
-pub enum WeaponSlot {
-	Melee,
-	Ranged,
-}
+pub struct WeaponOwner(pub Entity);
 
 // ============ Weapon Stat Components ============
 // Each weapon composes only the stats it needs
@@ -283,7 +472,7 @@ pub struct CooldownStats {
 
 #[derive(Component, Clone, Copy)]
 pub struct EffectStats {
-	pub base: f32, // For melee: stun duration, for projectiles: speed, etc.
+	pub base: f32, // For melee: stun duration, for summons: max summons, etc.
 }
 
 // ============ Upgrade Behavior System ============
@@ -298,3 +487,23 @@ pub enum UpgradeBehavior {
 
 #[derive(Component, Clone, Deserialize)]
 pub struct UpgradeBehaviors(pub Vec<UpgradeBehavior>);
+
+// ============ Upgrade Level Tables ============
+
+#[derive(Clone, Copy, Deserialize)]
+pub enum LevelStatDelta {
+	Damage(f32),      // Flat damage added to the base damage
+	Cooldown(f32),    // Seconds added to the base cooldown (negative = faster)
+	Effect(f32),      // Added to the base effect stat (e.g. melee stun duration, max summons)
+	AdditionalEntity, // Spawn one more instance (e.g. orbiting blades)
+}
+
+#[derive(Clone, Deserialize)]
+pub struct UpgradeLevelData {
+	pub description: String,
+	pub deltas: Vec<LevelStatDelta>,
+}
+
+/// Explicit per-level upgrades; entry 0 is applied when the weapon reaches level 2.
+#[derive(Component, Clone)]
+pub struct UpgradeLevels(pub Vec<UpgradeLevelData>);
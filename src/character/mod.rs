@@ -0,0 +1,202 @@
+use bevy::{asset::AssetLoader, ecs::system::SystemParam, prelude::*};
+use serde::Deserialize;
+
+mod select;
+
+pub struct CharacterPlugin;
+
+impl Plugin for CharacterPlugin {
+	fn build(&self, app: &mut App) {
+		app.init_asset::<CharacterData>()
+			.init_asset_loader::<CharacterDataLoader>()
+			.init_resource::<select::CharacterSelectState>()
+			.add_systems(
+				Update,
+				(
+					initialize_character_registry,
+					select::spawn_character_select,
+					select::handle_character_select,
+					apply_character_passives,
+				),
+			);
+	}
+}
+
+/// Stat bonus a character gets at the start of a run and again every `every_levels` levels.
+#[derive(Component, Deserialize, Clone)]
+pub struct CharacterPassive {
+	pub name: String,
+	pub description: String,
+	pub stat: crate::StatType,
+	pub value: f32,
+	pub every_levels: u32,
+}
+
+#[derive(Asset, TypePath, Deserialize, Clone)]
+pub struct CharacterData {
+	pub name: String,
+	pub description: String,
+	pub color: (f32, f32, f32), // Tints the sprite when one is set
+	#[serde(default)]
+	pub sprite: Option<String>, // Image path relative to assets/
+	pub speed: f32,
+	pub jump_force: f32,
+	pub max_health: f32,
+	pub max_energy: f32,
+	pub energy_regen: f32,
+	pub starting_weapons: Vec<crate::InitialWeapon>,
+	#[serde(default)]
+	pub passive: Option<CharacterPassive>,
+	// Level-up menu actions available at the start of a run
+	#[serde(default)]
+	pub rerolls: u32,
+	#[serde(default)]
+	pub skips: u32,
+	#[serde(default)]
+	pub banishes: u32,
+}
+
+#[derive(Default)]
+struct CharacterDataLoader;
+
+impl AssetLoader for CharacterDataLoader {
+	type Asset = CharacterData;
+	type Settings = ();
+	type Error = std::io::Error;
+
+	async fn load(
+		&self,
+		reader: &mut dyn bevy::asset::io::Reader,
+		_settings: &Self::Settings,
+		_load_context: &mut bevy::asset::LoadContext<'_>,
+	) -> Result<Self::Asset, Self::Error> {
+		let mut bytes = Vec::new();
+		reader.read_to_end(&mut bytes).await?;
+		let data = ron::de::from_bytes::<CharacterData>(&bytes)
+			.map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+		Ok(data)
+	}
+
+	fn extensions(&self) -> &[&str] {
+		&["character.ron"]
+	}
+}
+
+#[derive(Resource)]
+pub struct CharacterRegistry {
+	characters: std::collections::HashMap<String, Handle<CharacterData>>,
+	character_ids: Vec<String>, // Roster order on the select screen
+}
+
+impl CharacterRegistry {
+	pub fn get(&self, id: &str) -> Option<&Handle<CharacterData>> {
+		self.characters.get(id)
+	}
+
+	pub fn ids(&self) -> &[String] {
+		&self.character_ids
+	}
+}
+
+/// Character picked on the select screen. Players are only spawned once this exists.
+#[derive(Resource)]
+pub struct SelectedCharacter {
+	pub id: String,
+}
+
+#[derive(SystemParam)]
+pub struct CharacterResources<'w> {
+	pub registry: Option<Res<'w, CharacterRegistry>>,
+	pub assets: Res<'w, Assets<CharacterData>>,
+	pub selected: Option<Res<'w, SelectedCharacter>>,
+	pub asset_server: Res<'w, AssetServer>,
+}
+
+impl CharacterResources<'_> {
+	/// Player 1 plays the selected character; later players take the following characters in
+	/// the roster so co-op players can be told apart.
+	pub fn for_player(&self, index: usize) -> Option<&CharacterData> {
+		let registry = self.registry.as_ref()?;
+		let selected = self.selected.as_ref()?;
+		let ids = registry.ids();
+		let start = ids.iter().position(|id| *id == selected.id)?;
+		let id = &ids[(start + index) % ids.len()];
+
+		registry.get(id).and_then(|handle| self.assets.get(handle))
+	}
+
+	pub fn sprite(&self, character: &CharacterData) -> Handle<Image> {
+		character
+			.sprite
+			.as_ref()
+			.map(|path| self.asset_server.load(path.clone()))
+			.unwrap_or_default()
+	}
+}
+
+fn initialize_character_registry(
+	mut commands: Commands,
+	asset_server: Res<AssetServer>,
+	registry: Option<Res<CharacterRegistry>>,
+	game_config: Option<Res<crate::GameConfig>>,
+	config_assets: Res<Assets<crate::GameConfigData>>,
+) {
+	// Only initialize once
+	if registry.is_some() {
+		return;
+	}
+
+	// Wait for game config to load
+	let Some(config) = game_config else { return };
+	let Some(config_data) = config_assets.get(&config.config_handle) else {
+		return;
+	};
+
+	let characters = config_data
+		.character_ids
+		.iter()
+		.map(|id| {
+			let path = format!("characters/{}.character.ron", id);
+			(id.clone(), asset_server.load(path))
+		})
+		.collect();
+
+	commands.insert_resource(CharacterRegistry {
+		characters,
+		character_ids: config_data.character_ids.clone(),
+	});
+}
+
+type PassivePlayerQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		&'static CharacterPassive,
+		&'static mut crate::player::Player,
+		&'static mut crate::behaviors::Damageable,
+		&'static mut crate::behaviors::PlayerEnergy,
+		&'static mut crate::player::PlayerStats,
+	),
+>;
+
+// Re-apply each character's passive whenever its player reaches a multiple of every_levels
+fn apply_character_passives(
+	mut level_up_events: MessageReader<crate::experience::LevelUpEvent>,
+	mut player_query: PassivePlayerQuery,
+) {
+	for event in level_up_events.read() {
+		let Ok((passive, mut player, mut damageable, mut energy, mut stats)) =
+			player_query.get_mut(event.player)
+		else {
+			continue;
+		};
+
+		if passive.every_levels > 0 && event.level % passive.every_levels == 0 {
+			crate::powerups::application::apply_stat(
+				&passive.stat,
+				passive.value,
+				(&mut player, &mut damageable, &mut energy, &mut stats),
+			);
+		}
+	}
+}
@@ -0,0 +1,253 @@
+use bevy::prelude::*;
+
+#[derive(Resource, Default)]
+pub(super) struct CharacterSelectState {
+	showing: bool,
+	selected_index: usize,
+}
+
+#[derive(Component)]
+pub(super) struct CharacterSelectContainer;
+
+#[derive(Component)]
+pub(super) struct CharacterButton {
+	index: usize,
+}
+
+fn character_description(character: &super::CharacterData) -> String {
+	let stats = format!(
+		"Health {:.0} | Energy {:.0} | Speed {:.0} | Jump {:.0}\nRerolls {} | Skips {} | Banishes {}",
+		character.max_health,
+		character.max_energy,
+		character.speed,
+		character.jump_force,
+		character.rerolls,
+		character.skips,
+		character.banishes
+	);
+
+	match &character.passive {
+		Some(passive) => format!(
+			"{}\n{}\n{}: {}",
+			character.description, stats, passive.name, passive.description
+		),
+		None => format!("{}\n{}", character.description, stats),
+	}
+}
+
+// Show the roster once every character has loaded, pausing the game until one is picked
+pub(super) fn spawn_character_select(
+	mut commands: Commands,
+	mut select_state: ResMut<CharacterSelectState>,
+	characters: super::CharacterResources,
+	mut time: ResMut<Time<Virtual>>,
+) {
+	if select_state.showing || characters.selected.is_some() {
+		return;
+	}
+
+	let Some(registry) = characters.registry.as_ref() else {
+		return;
+	};
+
+	let roster: Option<Vec<&super::CharacterData>> = registry
+		.ids()
+		.iter()
+		.map(|id| {
+			registry
+				.get(id)
+				.and_then(|handle| characters.assets.get(handle))
+		})
+		.collect();
+	let Some(roster) = roster.filter(|roster| !roster.is_empty()) else {
+		return;
+	};
+
+	select_state.showing = true;
+	select_state.selected_index = 0;
+
+	// Pause the game
+	time.pause();
+
+	let container = commands
+		.spawn((
+			Node {
+				width: Val::Percent(100.0),
+				height: Val::Percent(100.0),
+				position_type: PositionType::Absolute,
+				justify_content: JustifyContent::Center,
+				align_items: AlignItems::Center,
+				..default()
+			},
+			BackgroundColor(Color::srgba(
+				0.0,
+				0.0,
+				0.0,
+				crate::constants::CHARACTER_SELECT_OVERLAY_ALPHA,
+			)),
+			CharacterSelectContainer,
+		))
+		.id();
+
+	let button_container = commands
+		.spawn(Node {
+			flex_direction: FlexDirection::Column,
+			row_gap: Val::Px(crate::constants::POWERUP_BUTTON_GAP),
+			..default()
+		})
+		.id();
+
+	commands.entity(container).add_child(button_container);
+
+	// Title
+	let title = commands
+		.spawn((
+			Text::new("Choose a Character:"),
+			TextFont {
+				font_size: crate::constants::UI_FONT_SIZE_LARGE,
+				..default()
+			},
+			TextColor(Color::srgb(0.9, 0.9, 0.3)),
+			Node {
+				margin: UiRect::bottom(Val::Px(crate::constants::POWERUP_TITLE_MARGIN)),
+				..default()
+			},
+		))
+		.id();
+
+	commands.entity(button_container).add_child(title);
+
+	// Create a button for each character
+	for (index, character) in roster.iter().enumerate() {
+		// First button is selected by default
+		let bg_color = if index == 0 {
+			crate::constants::POWERUP_COLOR_SELECTED
+		} else {
+			crate::constants::POWERUP_COLOR_NORMAL
+		};
+
+		let button = commands
+			.spawn((
+				Button,
+				Node {
+					width: Val::Px(crate::constants::POWERUP_BUTTON_WIDTH),
+					min_height: Val::Px(crate::constants::POWERUP_BUTTON_HEIGHT),
+					justify_content: JustifyContent::Center,
+					align_items: AlignItems::Center,
+					padding: UiRect::all(Val::Px(crate::constants::POWERUP_BUTTON_PADDING)),
+					..default()
+				},
+				BackgroundColor(bg_color),
+				CharacterButton { index },
+			))
+			.id();
+
+		let text_container = commands
+			.spawn(Node {
+				flex_direction: FlexDirection::Column,
+				..default()
+			})
+			.id();
+
+		// The name is drawn in the character's color
+		let (r, g, b) = character.color;
+		let name_text = commands
+			.spawn((
+				Text::new(character.name.clone()),
+				TextFont {
+					font_size: crate::constants::UI_FONT_SIZE_MEDIUM,
+					..default()
+				},
+				TextColor(Color::srgb(r, g, b)),
+			))
+			.id();
+
+		let desc_text = commands
+			.spawn((
+				Text::new(character_description(character)),
+				TextFont {
+					font_size: crate::constants::UI_FONT_SIZE_SMALL,
+					..default()
+				},
+				TextColor(Color::srgb(0.7, 0.7, 0.7)),
+			))
+			.id();
+
+		commands.entity(text_container).add_child(name_text);
+		commands.entity(text_container).add_child(desc_text);
+		commands.entity(button).add_child(text_container);
+		commands.entity(button_container).add_child(button);
+	}
+}
+
+type CharacterButtonQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		&'static CharacterButton,
+		&'static Interaction,
+		&'static mut BackgroundColor,
+	),
+>;
+
+// Keyboard, any gamepad or the mouse picks a character; the run starts once one is chosen
+pub(super) fn handle_character_select(
+	mut commands: Commands,
+	mut select_state: ResMut<CharacterSelectState>,
+	mut button_query: CharacterButtonQuery,
+	ui_query: Query<Entity, With<CharacterSelectContainer>>,
+	registry: Option<Res<super::CharacterRegistry>>,
+	input: crate::input::ActionInput,
+	mut time: ResMut<Time<Virtual>>,
+) {
+	if !select_state.showing {
+		return;
+	}
+
+	let Some(registry) = registry else {
+		return;
+	};
+
+	let num_options = registry.ids().len();
+	let mut chosen = None;
+
+	if input.menu_just_pressed(crate::input::InputAction::MenuUp) {
+		select_state.selected_index = (select_state.selected_index + num_options - 1) % num_options;
+	}
+	if input.menu_just_pressed(crate::input::InputAction::MenuDown) {
+		select_state.selected_index = (select_state.selected_index + 1) % num_options;
+	}
+	if input.menu_just_pressed(crate::input::InputAction::MenuConfirm) {
+		chosen = Some(select_state.selected_index);
+	}
+
+	for (button, interaction, mut bg_color) in button_query.iter_mut() {
+		let color = match *interaction {
+			Interaction::Pressed => {
+				chosen = Some(button.index);
+				crate::constants::POWERUP_COLOR_SELECTED
+			}
+			Interaction::Hovered => crate::constants::POWERUP_COLOR_HOVERED,
+			// Keep selected button highlighted even when mouse not hovering
+			Interaction::None if button.index == select_state.selected_index => {
+				crate::constants::POWERUP_COLOR_SELECTED
+			}
+			Interaction::None => crate::constants::POWERUP_COLOR_NORMAL,
+		};
+		if bg_color.0 != color {
+			bg_color.0 = color;
+		}
+	}
+
+	let Some(id) = chosen.and_then(|index| registry.ids().get(index)) else {
+		return;
+	};
+
+	commands.insert_resource(super::SelectedCharacter { id: id.clone() });
+
+	for entity in ui_query.iter() {
+		commands.entity(entity).despawn();
+	}
+	select_state.showing = false;
+	time.unpause();
+}
@@ -1,17 +1,72 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
 pub struct CombatPlugin;
 
+/// Damage a player's weapons dealt, used for lifesteal.
+#[derive(Message)]
+pub struct DamageDealtEvent {
+	pub player: Entity,
+	pub amount: f32,
+}
+
+// Scales hits by the attacker's might and the target's armor, and reports lifesteal
+#[derive(SystemParam)]
+pub struct CombatStats<'w, 's> {
+	stats: Query<'w, 's, &'static crate::player::PlayerStats>,
+	damage_dealt: MessageWriter<'w, DamageDealtEvent>,
+}
+
+impl CombatStats<'_, '_> {
+	pub fn owner_stats(&self, owner: Option<Entity>) -> crate::player::PlayerStats {
+		crate::player::PlayerStats::for_owner(&self.stats, owner)
+	}
+
+	// Final damage of one hit from owner's weapon on target
+	pub fn hit(&mut self, owner: Option<Entity>, target: Entity, damage: f32) -> f32 {
+		self.hit_over_time(owner, target, damage, 1.0)
+	}
+
+	// Continuous damage goes through armor per second rather than per frame
+	pub fn hit_over_time(
+		&mut self,
+		owner: Option<Entity>,
+		target: Entity,
+		damage_per_second: f32,
+		delta: f32,
+	) -> f32 {
+		let owner_stats = self.owner_stats(owner);
+		let mut damage_per_second = damage_per_second * owner_stats.damage_multiplier();
+		if let Ok(target_stats) = self.stats.get(target) {
+			damage_per_second = target_stats.damage_taken(damage_per_second);
+		}
+		let damage = damage_per_second * delta;
+
+		if let Some(player) = owner {
+			if owner_stats.lifesteal > 0.0 && damage > 0.0 {
+				self.damage_dealt.write(DamageDealtEvent {
+					player,
+					amount: damage,
+				});
+			}
+		}
+		damage
+	}
+}
+
 type DamageableQuery<'w, 's> = Query<
 	'w,
 	's,
 	(
+		Entity,
 		&'static Transform,
 		&'static Sprite,
 		&'static mut crate::behaviors::Damageable,
 		Has<crate::behaviors::EnemyTag>,
 		Has<crate::behaviors::PlayerTag>,
+		Has<crate::behaviors::AllyTag>,
 	),
+	Without<crate::behaviors::Invulnerable>,
 >;
 
 type DeathQuery<'w, 's> = Query<
@@ -22,18 +77,20 @@ type DeathQuery<'w, 's> = Query<
 		&'static Transform,
 		&'static crate::behaviors::Damageable,
 		Has<crate::behaviors::EnemyTag>,
+		Has<crate::behaviors::AllyTag>,
 		Option<&'static crate::enemy::Enemy>,
 	),
 >;
 
 impl Plugin for CombatPlugin {
 	fn build(&self, app: &mut App) {
-		app.add_systems(
+		app.add_message::<DamageDealtEvent>().add_systems(
 			Update,
 			(
 				apply_contact_damage,
 				handle_explosion_proximity,
 				handle_damageable_death,
+				tick_invulnerability,
 			)
 				.after(crate::physics::PhysicsSet)
 				.before(crate::physics::CollisionResolutionSet),
@@ -49,23 +106,34 @@ fn apply_contact_damage(
 		&Transform,
 		&Sprite,
 		&crate::behaviors::DamageOnContact,
+		Option<&crate::behaviors::WeaponOwner>,
 	)>,
 	mut damageables: DamageableQuery,
+	mut combat_stats: CombatStats,
 	time: Res<Time<Virtual>>,
 ) {
 	use crate::behaviors::*;
 
-	for (dealer_entity, dealer_transform, dealer_sprite, damage_on_contact) in damage_dealers.iter()
+	for (dealer_entity, dealer_transform, dealer_sprite, damage_on_contact, owner) in
+		damage_dealers.iter()
 	{
 		let dealer_size = dealer_sprite.custom_size.unwrap_or(Vec2::ONE);
+		let owner = owner.map(|owner| owner.0);
 
-		for (target_transform, target_sprite, mut damageable, is_enemy, is_player) in
-			damageables.iter_mut()
+		for (
+			target_entity,
+			target_transform,
+			target_sprite,
+			mut damageable,
+			is_enemy,
+			is_player,
+			is_ally,
+		) in damageables.iter_mut()
 		{
-			// Check if target matches the damage filter
+			// Check if target matches the damage filter (allies take hits aimed at the player)
 			let target_matches = match damage_on_contact.targets {
 				TargetFilter::Enemies => is_enemy,
-				TargetFilter::Player => is_player,
+				TargetFilter::Player => is_player || is_ally,
 				TargetFilter::All => true,
 			};
 
@@ -83,10 +151,16 @@ fn apply_contact_damage(
 			) {
 				match damage_on_contact.damage_type {
 					DamageType::Continuous => {
-						damageable.health -= damage_on_contact.damage * time.delta_secs();
+						damageable.health -= combat_stats.hit_over_time(
+							owner,
+							target_entity,
+							damage_on_contact.damage,
+							time.delta_secs(),
+						);
 					}
 					DamageType::OneTime => {
-						damageable.health -= damage_on_contact.damage;
+						damageable.health -=
+							combat_stats.hit(owner, target_entity, damage_on_contact.damage);
 						// Despawn one-time damage dealers (like projectiles)
 						commands.entity(dealer_entity).despawn();
 						break; // Stop after first hit
@@ -100,20 +174,38 @@ fn apply_contact_damage(
 // Explosion proximity system
 fn handle_explosion_proximity(
 	mut commands: Commands,
-	exploders: Query<(Entity, &Transform, &crate::behaviors::ExplodeOnProximity)>,
+	exploders: Query<(
+		Entity,
+		&Transform,
+		&crate::behaviors::ExplodeOnProximity,
+		Option<&crate::behaviors::WeaponOwner>,
+	)>,
 	mut targets: DamageableQuery,
 	health_bar_query: Query<(Entity, &crate::enemy::HealthBar)>,
+	mut combat_stats: CombatStats,
 ) {
 	use crate::behaviors::TargetFilter;
 
-	for (exploder_entity, exploder_transform, explosion_behavior) in exploders.iter() {
-		for (target_transform, _target_sprite, mut damageable, is_enemy, is_player) in
-			targets.iter_mut()
+	for (exploder_entity, exploder_transform, explosion_behavior, owner) in exploders.iter() {
+		let owner = owner.map(|owner| owner.0);
+		// Area grows the blast of player-owned exploders
+		let trigger_range =
+			explosion_behavior.trigger_range * combat_stats.owner_stats(owner).area_multiplier();
+
+		for (
+			target_entity,
+			target_transform,
+			_target_sprite,
+			mut damageable,
+			is_enemy,
+			is_player,
+			is_ally,
+		) in targets.iter_mut()
 		{
 			// Check if target matches the explosion target filter
 			let target_matches = match explosion_behavior.targets {
 				TargetFilter::Enemies => is_enemy,
-				TargetFilter::Player => is_player,
+				TargetFilter::Player => is_player || is_ally,
 				TargetFilter::All => true,
 			};
 
@@ -125,12 +217,13 @@ fn handle_explosion_proximity(
 				.translation
 				.distance(target_transform.translation);
 
-			if distance <= explosion_behavior.trigger_range {
+			if distance <= trigger_range {
 				// Apply damage
-				damageable.health -= explosion_behavior.damage;
+				damageable.health -=
+					combat_stats.hit(owner, target_entity, explosion_behavior.damage);
 
 				// Spawn explosion visual effect
-				let explosion_size = explosion_behavior.trigger_range * 2.0;
+				let explosion_size = trigger_range * 2.0;
 				commands.spawn((
 					Sprite {
 						color: Color::srgba(1.0, 0.5, 0.0, 0.7), // Orange with transparency
@@ -160,31 +253,63 @@ fn handle_explosion_proximity(
 	}
 }
 
+// Remove invulnerability once its timer runs out
+fn tick_invulnerability(
+	mut commands: Commands,
+	mut query: Query<(Entity, &mut crate::behaviors::Invulnerable)>,
+	time: Res<Time<Virtual>>,
+) {
+	for (entity, mut invulnerable) in query.iter_mut() {
+		invulnerable.timer.tick(time.delta());
+		if invulnerable.timer.is_finished() {
+			commands
+				.entity(entity)
+				.remove::<crate::behaviors::Invulnerable>();
+		}
+	}
+}
+
 // Generic death handling
+#[allow(clippy::too_many_arguments)]
 fn handle_damageable_death(
 	mut commands: Commands,
 	query: DeathQuery,
 	health_bar_query: Query<(Entity, &crate::enemy::HealthBar)>,
-	weapon_query: Query<Entity, With<crate::behaviors::WeaponId>>,
-	weapon_ui_query: Query<Entity, With<crate::weapons::WeaponCooldownBar>>,
-	mut weapon_inventory: Option<ResMut<crate::weapons::WeaponInventory>>,
+	owned_query: Query<(Entity, &crate::behaviors::WeaponOwner)>,
+	weapon_ui_query: Query<(Entity, &crate::weapons::WeaponCooldownBar)>,
+	player_stats: Query<&crate::player::PlayerStats>,
+	game_config: Option<Res<crate::GameConfig>>,
+	config_assets: Res<Assets<crate::GameConfigData>>,
 ) {
-	for (entity, transform, damageable, is_enemy, enemy_data) in query.iter() {
+	for (entity, transform, damageable, is_enemy, is_ally, enemy_data) in query.iter() {
 		if damageable.health <= 0.0 {
-			// If it's an enemy, spawn XP orb
+			// If it's an enemy, spawn XP orb and drops
 			if is_enemy {
 				if let Some(enemy) = enemy_data {
-					commands.spawn((
-						Sprite {
-							color: crate::constants::XP_ORB_COLOR,
-							custom_size: Some(crate::constants::XP_ORB_SIZE),
-							..default()
-						},
-						Transform::from_translation(transform.translation),
-						crate::experience::ExperienceOrb {
-							value: enemy.xp_value,
-						},
-					));
+					crate::experience::spawn_xp_orb(
+						&mut commands,
+						transform.translation,
+						enemy.xp_value,
+					);
+
+					// Roll the enemy's drop table
+					if let Some(config_data) = game_config
+						.as_ref()
+						.and_then(|config| config_assets.get(&config.config_handle))
+					{
+						// The luckiest player's luck applies to shared drops
+						let luck = player_stats
+							.iter()
+							.map(|stats| stats.luck_multiplier())
+							.fold(1.0, f32::max);
+						crate::pickups::spawn_drops(
+							&mut commands,
+							&enemy.drops,
+							transform.translation,
+							&config_data.pickups,
+							luck,
+						);
+					}
 
 					// Despawn health bars
 					for (bar_entity, health_bar) in health_bar_query.iter() {
@@ -193,17 +318,20 @@ fn handle_damageable_death(
 						}
 					}
 				}
-			} else {
-				// Player died - clean up all weapons and weapon UI
-				for weapon_entity in weapon_query.iter() {
-					commands.entity(weapon_entity).despawn();
-				}
-				for ui_entity in weapon_ui_query.iter() {
-					commands.entity(ui_entity).despawn();
+			} else if !is_ally {
+				// Player died - clean up everything their weapons own, along with its UI
+				for (owned_entity, owner) in owned_query.iter() {
+					if owner.0 == entity {
+						commands.entity(owned_entity).despawn();
+					}
 				}
-				// Clear weapon inventory
-				if let Some(inventory) = &mut weapon_inventory {
-					inventory.weapons.clear();
+				for (ui_entity, bar) in weapon_ui_query.iter() {
+					if owned_query
+						.get(bar.weapon_entity)
+						.is_ok_and(|(_, owner)| owner.0 == entity)
+					{
+						commands.entity(ui_entity).despawn();
+					}
 				}
 			}
 
@@ -4,14 +4,15 @@ use bevy::prelude::*;
 
 pub const GRAVITY: f32 = -980.0;
 pub const GROUND_SNAP_DISTANCE: f32 = 10.0;
+pub const WALL_CONTACT_DISTANCE: f32 = 2.0;
+pub const PLATFORM_DROP_THROUGH_TIME: f32 = 0.25;
+pub const PROJECTILE_IMPACT_SIZE: Vec2 = Vec2::new(12.0, 12.0);
+pub const PROJECTILE_IMPACT_DURATION: f32 = 0.1;
 
 // ============ Player Constants ============
 
 pub const PLAYER_DEFAULT_SPEED: f32 = 300.0;
 pub const PLAYER_DEFAULT_JUMP_FORCE: f32 = 400.0;
-pub const PLAYER_DEFAULT_HEALTH: f32 = 100.0;
-pub const PLAYER_DEFAULT_ENERGY: f32 = 100.0;
-pub const PLAYER_ENERGY_REGEN_RATE: f32 = 0.0;
 pub const ENERGY_CHARGE_RATE: f32 = 50.0;
 pub const REPULSION_FORCE_DEFAULT: f32 = 10.0;
 pub const MAX_REPULSION_FORCE: f32 = 70.0;
@@ -20,20 +21,50 @@ pub const REPULSION_RANGE: f32 = 200.0;
 pub const REPULSION_BASE_SPEED: f32 = 250.0;
 pub const PLAYER_SIZE: Vec2 = Vec2::new(40.0, 40.0);
 pub const PLAYER_SPAWN_POSITION: Vec3 = Vec3::new(0.0, -200.0, 0.0);
-pub const PLAYER_COLOR: Color = Color::srgb(0.2, 0.4, 0.9);
+pub const PLAYER_JOIN_OFFSET: Vec3 = Vec3::new(50.0, 0.0, 0.0);
+pub const MAX_PLAYERS: usize = 2;
 pub const PLAYER_ACCELERATION: f32 = 2000.0;
 pub const PLAYER_DECELERATION: f32 = 800.0;
+pub const PLAYER_COYOTE_TIME: f32 = 0.1;
+pub const PLAYER_JUMP_BUFFER_TIME: f32 = 0.12;
+pub const PLAYER_JUMP_CUT_MULTIPLIER: f32 = 0.5;
+pub const PLAYER_DEFAULT_EXTRA_JUMPS: u32 = 0;
+pub const PLAYER_WALL_SLIDE_SPEED: f32 = 120.0;
+pub const PLAYER_WALL_JUMP_PUSH: f32 = 350.0;
+pub const PLAYER_WALL_JUMP_LOCK_TIME: f32 = 0.15;
+pub const PLAYER_DASH_SPEED: f32 = 900.0;
+pub const PLAYER_DASH_DURATION: f32 = 0.15;
+pub const PLAYER_DASH_COOLDOWN: f32 = 1.0;
+pub const PLAYER_DASH_MIN_COOLDOWN: f32 = 0.2;
+pub const PLAYER_DASH_ENERGY_COST: f32 = 15.0;
+pub const PLAYER_DASH_IFRAME_TIME: f32 = 0.25;
+pub const PLAYER_DEFAULT_DASH_CHARGES: u32 = 1;
+pub const PLAYER_DEFAULT_PICKUP_RADIUS: f32 = 150.0; // XP orbs inside this range fly to the player
+pub const PLAYER_DEFAULT_XP_MULTIPLIER: f32 = 1.0;
+pub const PLAYER_MAX_COOLDOWN_REDUCTION: f32 = 0.8; // Weapons never fire more than 5x faster
+pub const PLAYER_MIN_AREA_MULTIPLIER: f32 = 0.1;
+pub const PLAYER_MIN_ARMOR_DAMAGE: f32 = 0.1; // Fraction of each hit that always gets through armor
 
 // ============ Input Constants ============
 
 pub const GAMEPAD_DEADZONE: f32 = 0.1;
 
+// Written by the controls screen (F1), read at startup
+pub const INPUT_BINDINGS_PATH: &str = "input_bindings.ron";
+
+pub const REBIND_OVERLAY_ALPHA: f32 = 0.85;
+pub const REBIND_ROW_WIDTH: f32 = 420.0;
+pub const REBIND_ROW_GAP: f32 = 4.0;
+pub const REBIND_SLOTS: usize = 2; // Keys (and buttons) each action can hold on the controls screen
+
 // ============ Enemy Constants ============
 
 pub const ENEMY_SPAWN_TIMER: f32 = 2.0;
 pub const ENEMY_SPAWN_DISTANCE: f32 = 700.0;
 pub const ENEMY_SPAWN_Y_MIN: f32 = -200.0;
 pub const ENEMY_SPAWN_Y_MAX: f32 = 100.0;
+// Ground enemies drop through one-way platforms when the player is this far below
+pub const ENEMY_DROP_THROUGH_HEIGHT: f32 = 60.0;
 
 pub const WAVE_DURATION: f32 = 30.0;
 pub const WAVE_HEALTH_SCALING: f32 = 0.2;
@@ -48,16 +79,37 @@ pub const HEALTH_BAR_OFFSET_Y: f32 = 8.0;
 
 pub const INITIAL_XP_TO_NEXT_LEVEL: u32 = 100;
 pub const XP_LEVEL_SCALING: f32 = 1.5;
-pub const XP_ORB_ATTRACTION_RANGE: f32 = 150.0;
 pub const XP_ORB_MOVEMENT_SPEED: f32 = 300.0;
 pub const XP_ORB_COLLECTION_RANGE: f32 = 30.0;
-pub const XP_ORB_SIZE: Vec2 = Vec2::new(15.0, 15.0);
-pub const XP_ORB_COLOR: Color = Color::srgb(0.9, 0.7, 0.2);
+// (minimum value, size, color), lowest tier first; merged orbs move up the tiers
+pub const XP_ORB_TIERS: [(u32, Vec2, Color); 4] = [
+	(0, Vec2::new(15.0, 15.0), Color::srgb(0.9, 0.7, 0.2)),
+	(25, Vec2::new(19.0, 19.0), Color::srgb(0.3, 0.9, 0.4)),
+	(100, Vec2::new(23.0, 23.0), Color::srgb(0.3, 0.7, 1.0)),
+	(400, Vec2::new(28.0, 28.0), Color::srgb(0.9, 0.3, 0.9)),
+];
+pub const XP_ORB_MERGE_RADIUS: f32 = 40.0;
+pub const XP_ORB_MERGE_INTERVAL: f32 = 0.25;
+// Beyond this many orbs the smallest are pooled into their nearest neighbor
+pub const XP_ORB_MAX_COUNT: usize = 150;
+pub const XP_MAGNET_PULL_SPEED: f32 = 700.0;
+
+// ============ Pickup Constants ============
+
+// Drops land up to this far from where the enemy died
+pub const PICKUP_SCATTER: f32 = 15.0;
 
 // ============ Powerup Constants ============
 
 pub const POWERUP_OPTIONS_COUNT: usize = 3;
 pub const POWERUP_OVERLAY_ALPHA: f32 = 0.1;
+pub const POWERUP_SKIP_HEAL: f32 = 15.0;
+pub const POWERUP_DEFAULT_WEIGHT: f32 = 1.0;
+pub const POWERUP_WEIGHT_COMMON: f32 = 1.0;
+pub const POWERUP_WEIGHT_UNCOMMON: f32 = 0.6;
+pub const POWERUP_WEIGHT_RARE: f32 = 0.3;
+pub const POWERUP_WEIGHT_EPIC: f32 = 0.1;
+pub const CHARACTER_SELECT_OVERLAY_ALPHA: f32 = 0.85;
 
 // ============ UI Constants ============
 
@@ -79,8 +131,11 @@ pub const ENERGY_BAR_TOP: f32 = 70.0;
 pub const ENERGY_BAR_COLOR_BG: Color = Color::srgb(0.2, 0.2, 0.2);
 pub const ENERGY_BAR_COLOR_FG: Color = Color::srgb(0.9, 0.7, 0.2);
 
+pub const PLAYER_HUD_SPACING: f32 = 100.0; // Vertical offset between each player's stats and bars
+
 pub const POWERUP_BUTTON_WIDTH: f32 = 400.0;
 pub const POWERUP_BUTTON_HEIGHT: f32 = 80.0;
+pub const POWERUP_ACTION_BUTTON_HEIGHT: f32 = 40.0;
 pub const POWERUP_BUTTON_PADDING: f32 = 10.0;
 pub const POWERUP_BUTTON_GAP: f32 = 20.0;
 pub const POWERUP_TITLE_MARGIN: f32 = 30.0;
@@ -88,6 +143,12 @@ pub const POWERUP_TITLE_MARGIN: f32 = 30.0;
 pub const POWERUP_COLOR_SELECTED: Color = Color::srgb(0.3, 0.3, 0.5);
 pub const POWERUP_COLOR_NORMAL: Color = Color::srgb(0.2, 0.2, 0.3);
 pub const POWERUP_COLOR_HOVERED: Color = Color::srgb(0.3, 0.3, 0.4);
+pub const POWERUP_COLOR_EVOLUTION: Color = Color::srgb(1.0, 0.8, 0.2);
+pub const POWERUP_COLOR_COMMON: Color = Color::srgb(0.5, 0.5, 0.6);
+pub const POWERUP_COLOR_UNCOMMON: Color = Color::srgb(0.3, 0.8, 0.4);
+pub const POWERUP_COLOR_RARE: Color = Color::srgb(0.3, 0.5, 1.0);
+pub const POWERUP_COLOR_EPIC: Color = Color::srgb(0.8, 0.3, 0.9);
+pub const POWERUP_CARD_BORDER: f32 = 2.0;
 
 // ============ Weapon Constants ============
 
@@ -101,6 +162,20 @@ pub const WEAPON_RANGE_BOOST_AMOUNT: f32 = 100.0;
 // Note: Upgrade behaviors are now defined in weapon data files (upgrade_behaviors field)
 pub const WEAPON_DAMAGE_INCREASE_PER_LEVEL: f32 = 0.2; // +20% damage per level (default for sync)
 
+// Lower bound for cooldowns reduced by weapon level tables
+pub const WEAPON_MIN_COOLDOWN: f32 = 0.05;
+
+// Angle in radians between extra projectiles added by the Amount stat
+pub const PROJECTILE_AMOUNT_SPREAD_ANGLE: f32 = 0.15;
+
+// ============ Boomerang Constants ============
+
+// Distance from its owner at which a returning boomerang is caught
+pub const BOOMERANG_CATCH_DISTANCE: f32 = 30.0;
+
+// Spin speed of boomerang projectiles in radians per second
+pub const BOOMERANG_SPIN_SPEED: f32 = 15.0;
+
 // ============ Melee Attack Constants ============
 
 // Movement speed when tracking enemies during melee attacks
@@ -121,3 +196,6 @@ pub const MELEE_KNOCKBACK_FORCE: f32 = 400.0;
 // ============ Platform Constants ============
 
 pub const PLATFORM_COLOR: Color = Color::srgb(0.3, 0.3, 0.3);
+
+// Timed platforms blink for this long before disappearing
+pub const TIMED_PLATFORM_WARNING_TIME: f32 = 0.5;
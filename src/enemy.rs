@@ -1,5 +1,5 @@
 use bevy::{asset::AssetLoader, prelude::*};
-use rand::Rng;
+use rand::{seq::SliceRandom, Rng};
 use serde::Deserialize;
 
 pub struct EnemyPlugin;
@@ -64,6 +64,7 @@ struct WaveTimer {
 #[derive(Component)]
 pub struct Enemy {
 	pub xp_value: u32,
+	pub drops: Vec<crate::pickups::DropData>,
 }
 
 #[derive(Asset, TypePath, Deserialize, Clone)]
@@ -72,6 +73,8 @@ pub struct EnemyData {
 	pub base_health: f32,
 	pub size: (f32, f32),
 	pub xp_value: u32,
+	#[serde(default)]
+	pub drops: Vec<crate::pickups::DropData>,
 	pub behaviors: Vec<crate::behaviors::BehaviorData>,
 }
 
@@ -213,6 +216,8 @@ fn apply_enemy_behaviors(
 				spawn_logic,
 				fire_range,
 				energy_cost: _,
+				motion,
+				platform_collision,
 			} => {
 				let mut timer = Timer::from_seconds(*cooldown, TimerMode::Repeating);
 				timer.tick(std::time::Duration::from_secs_f32(*cooldown));
@@ -224,10 +229,13 @@ fn apply_enemy_behaviors(
 						lifetime: *lifetime,
 						size: *projectile_size,
 						color: *projectile_color,
+						motion: *motion,
+						platform_collision: *platform_collision,
 					},
 					spawn_logic: spawn_logic.clone(),
 					fire_range: *fire_range,
 					energy_cost: 0.0, // Enemies don't use energy
+					spiral_angle: rand::random::<f32>() * 2.0 * std::f32::consts::PI,
 				});
 			}
 			BehaviorData::ExplodeOnProximity {
@@ -245,7 +253,7 @@ fn apply_enemy_behaviors(
 				entity_commands.insert(FlyingMovement);
 			}
 			_ => {
-				// Other behaviors (Orbiting, MeleeAttack, FollowPlayer) are not used by enemies
+				// Other behaviors (Orbiting, Beam, Deployable, Summon, MeleeAttack, FollowPlayer) are not used by enemies
 			}
 		}
 	}
@@ -269,15 +277,17 @@ fn spawn_enemies(
 	time: Res<Time<Virtual>>,
 	mut timer: ResMut<EnemySpawnTimer>,
 	wave: Res<WaveTimer>,
-	player_query: Query<(&Transform, &crate::player::Player), With<crate::player::Player>>,
+	player_query: Query<&Transform, With<crate::player::Player>>,
 	enemy_registry: Option<Res<EnemyRegistry>>,
 	enemy_data_assets: Res<Assets<EnemyData>>,
 ) {
 	if timer.0.tick(time.delta()).just_finished() {
 		let mut rng = rand::thread_rng();
 
-		if let (Ok((player_transform, _player)), Some(registry)) =
-			(player_query.single(), enemy_registry)
+		// In co-op, enemies spawn around a random player
+		let players: Vec<&Transform> = player_query.iter().collect();
+
+		if let (Some(player_transform), Some(registry)) = (players.choose(&mut rng), enemy_registry)
 		{
 			// Spawn enemies off-screen
 			let spawn_side = if rng.gen_bool(0.5) { 1.0 } else { -1.0 };
@@ -312,6 +322,7 @@ fn spawn_enemies(
 				Transform::from_xyz(spawn_x, spawn_y, 0.0),
 				Enemy {
 					xp_value: enemy_data.xp_value,
+					drops: enemy_data.drops.clone(),
 				},
 				crate::behaviors::Damageable {
 					health: scaled_health,
@@ -320,6 +331,7 @@ fn spawn_enemies(
 				crate::behaviors::EnemyTag,
 				crate::physics::Velocity { x: 0.0, y: 0.0 },
 				crate::physics::Grounded(false),
+				crate::physics::DropThrough::default(),
 				crate::physics::Collider,
 			));
 
@@ -375,8 +387,8 @@ fn update_wave(
 		wave.wave += 1;
 	}
 
-	// Calculate spawn rate based on both wave and player level
-	if let Ok(player) = player_query.single() {
+	// Calculate spawn rate based on both wave and the highest player level
+	if let Some(player) = player_query.iter().max_by_key(|player| player.level) {
 		let wave_reduction = wave.wave as f32 * crate::constants::WAVE_SPAWN_RATE_SCALING;
 		let level_reduction =
 			(player.level.saturating_sub(1)) as f32 * crate::constants::LEVEL_SPAWN_RATE_SCALING;
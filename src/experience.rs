@@ -1,90 +1,418 @@
 use bevy::prelude::*;
+use serde::Deserialize;
 
 pub struct ExperiencePlugin;
 
 impl Plugin for ExperiencePlugin {
 	fn build(&self, app: &mut App) {
-		app.insert_resource(PlayerExperience {
-			current_xp: 0,
-			xp_to_next_level: crate::constants::INITIAL_XP_TO_NEXT_LEVEL,
-		})
-		.add_message::<LevelUpEvent>()
-		.add_systems(
-			Update,
-			(move_xp_orbs_to_player, collect_experience, check_level_up),
-		);
+		app.add_message::<LevelUpEvent>()
+			.insert_resource(OrbMergeTimer(Timer::from_seconds(
+				crate::constants::XP_ORB_MERGE_INTERVAL,
+				TimerMode::Repeating,
+			)))
+			.add_systems(
+				Update,
+				// Merging runs after collection so an orb is never counted both on its own and
+				// inside the orb that absorbed it
+				(
+					move_xp_orbs_to_player,
+					collect_experience,
+					merge_xp_orbs,
+					check_level_up,
+				)
+					.chain(),
+			);
 	}
 }
 
-#[derive(Resource)]
+#[derive(Component)]
 pub struct PlayerExperience {
 	pub current_xp: u32,
 	pub xp_to_next_level: u32,
 }
 
+impl Default for PlayerExperience {
+	fn default() -> Self {
+		Self {
+			current_xp: 0,
+			xp_to_next_level: crate::constants::INITIAL_XP_TO_NEXT_LEVEL,
+		}
+	}
+}
+
 #[derive(Message)]
-pub struct LevelUpEvent;
+pub struct LevelUpEvent {
+	pub player: Entity,
+	pub level: u32, // Level just reached; several events can arrive for one player in a frame
+}
+
+/// XP needed to go from one level to the next, from game_config.ron.
+#[derive(Deserialize, Clone)]
+pub enum XpCurve {
+	Scaling { base: u32, multiplier: f32 }, // base * multiplier^(level - 1)
+	Table(Vec<u32>), // XP for level 1 -> 2, 2 -> 3, ...; the last entry repeats past the end
+}
+
+impl Default for XpCurve {
+	fn default() -> Self {
+		Self::Scaling {
+			base: crate::constants::INITIAL_XP_TO_NEXT_LEVEL,
+			multiplier: crate::constants::XP_LEVEL_SCALING,
+		}
+	}
+}
+
+impl XpCurve {
+	pub fn xp_to_next_level(&self, level: u32) -> u32 {
+		let xp = match self {
+			XpCurve::Scaling { base, multiplier } => {
+				(*base as f32 * multiplier.powi(level.saturating_sub(1) as i32)) as u32
+			}
+			XpCurve::Table(table) => {
+				let index = (level.saturating_sub(1) as usize).min(table.len().saturating_sub(1));
+				table.get(index).copied().unwrap_or(0)
+			}
+		};
+		// Never zero, or a single orb would level forever
+		xp.max(1)
+	}
+}
+
+#[derive(Deserialize, Clone)]
+pub enum LevelRewardType {
+	ExtraOptions(usize), // More cards on every later level-up menu
+	Stat(crate::StatType, f32),
+	Rerolls(u32),
+	Skips(u32),
+	Banishes(u32),
+}
+
+/// Bonus granted on reaching a level, on top of the usual powerup choice.
+#[derive(Deserialize, Clone)]
+pub struct LevelReward {
+	pub level: u32,
+	pub reward: LevelRewardType,
+}
 
 #[derive(Component)]
 pub struct ExperienceOrb {
 	pub value: u32,
 }
 
-fn move_xp_orbs_to_player(
-	mut orb_query: Query<&mut Transform, (With<ExperienceOrb>, Without<crate::player::Player>)>,
+/// Orb being pulled in by a magnet pickup, from any distance.
+#[derive(Component)]
+pub struct Magnetized {
+	pub player: Entity, // Player who picked up the magnet
+}
+
+#[derive(Resource)]
+struct OrbMergeTimer(Timer);
+
+// Size and color of the highest tier the value reaches
+fn orb_tier(value: u32) -> (Vec2, Color) {
+	let tiers = &crate::constants::XP_ORB_TIERS;
+	let (_, size, color) = tiers
+		.iter()
+		.rev()
+		.find(|(min_value, _, _)| value >= *min_value)
+		.unwrap_or(&tiers[0]);
+	(*size, *color)
+}
+
+pub fn spawn_xp_orb(commands: &mut Commands, position: Vec3, value: u32) {
+	let (size, color) = orb_tier(value);
+	commands.spawn((
+		Sprite {
+			color,
+			custom_size: Some(size),
+			..default()
+		},
+		Transform::from_translation(position),
+		ExperienceOrb { value },
+	));
+}
+
+type MergeOrbQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'static Transform,
+		&'static mut ExperienceOrb,
+		&'static mut Sprite,
+	),
+	Without<Magnetized>,
+>;
+
+// Fold nearby orbs into one larger orb, then pool the smallest orbs into their nearest
+// neighbor while there are more than XP_ORB_MAX_COUNT
+fn merge_xp_orbs(
+	mut commands: Commands,
+	mut orb_query: MergeOrbQuery,
 	player_query: Query<&Transform, With<crate::player::Player>>,
+	mut merge_timer: ResMut<OrbMergeTimer>,
 	time: Res<Time<Virtual>>,
 ) {
-	if let Ok(player_transform) = player_query.single() {
-		for mut orb_transform in orb_query.iter_mut() {
-			let distance = player_transform
-				.translation
-				.distance(orb_transform.translation);
-
-			// Attract orbs within range
-			if distance < crate::constants::XP_ORB_ATTRACTION_RANGE {
-				let direction =
-					(player_transform.translation - orb_transform.translation).normalize();
-				orb_transform.translation +=
-					direction * crate::constants::XP_ORB_MOVEMENT_SPEED * time.delta_secs();
+	merge_timer.0.tick(time.delta());
+	if !merge_timer.0.just_finished() {
+		return;
+	}
+
+	// Largest first, so small orbs are absorbed into big ones; orbs a player is about to
+	// collect are left alone
+	let mut orbs: Vec<(Entity, Vec3, u32)> = orb_query
+		.iter()
+		.map(|(entity, transform, orb, _)| (entity, transform.translation, orb.value))
+		.filter(|(_, position, _)| {
+			!player_query.iter().any(|player_transform| {
+				player_transform.translation.distance(*position)
+					< crate::constants::XP_ORB_COLLECTION_RANGE
+			})
+		})
+		.collect();
+	orbs.sort_by_key(|(_, _, value)| std::cmp::Reverse(*value));
+
+	let mut absorbed = vec![false; orbs.len()];
+	for i in 0..orbs.len() {
+		if absorbed[i] {
+			continue;
+		}
+		for j in (i + 1)..orbs.len() {
+			if !absorbed[j] && orbs[i].1.distance(orbs[j].1) < crate::constants::XP_ORB_MERGE_RADIUS
+			{
+				orbs[i].2 += orbs[j].2;
+				absorbed[j] = true;
 			}
 		}
 	}
+
+	let mut remaining: Vec<usize> = (0..orbs.len()).filter(|i| !absorbed[*i]).collect();
+	while remaining.len() > crate::constants::XP_ORB_MAX_COUNT {
+		let Some(smallest) = remaining.pop() else {
+			break;
+		};
+		let nearest = remaining.iter().copied().min_by(|a, b| {
+			let dist_a = orbs[*a].1.distance(orbs[smallest].1);
+			let dist_b = orbs[*b].1.distance(orbs[smallest].1);
+			dist_a.partial_cmp(&dist_b).unwrap()
+		});
+		if let Some(nearest) = nearest {
+			orbs[nearest].2 += orbs[smallest].2;
+			absorbed[smallest] = true;
+		}
+	}
+
+	for (index, (entity, _, value)) in orbs.iter().enumerate() {
+		if absorbed[index] {
+			commands.entity(*entity).despawn();
+			continue;
+		}
+
+		let Ok((_, _, mut orb, mut sprite)) = orb_query.get_mut(*entity) else {
+			continue;
+		};
+		if orb.value != *value {
+			orb.value = *value;
+			let (size, color) = orb_tier(*value);
+			sprite.custom_size = Some(size);
+			sprite.color = color;
+		}
+	}
 }
 
+type MovingOrbQuery<'w, 's> = Query<
+	'w,
+	's,
+	(&'static mut Transform, Option<&'static Magnetized>),
+	(With<ExperienceOrb>, Without<crate::player::Player>),
+>;
+
+fn move_xp_orbs_to_player(
+	mut orb_query: MovingOrbQuery,
+	player_query: Query<(&Transform, &crate::player::Player)>,
+	time: Res<Time<Virtual>>,
+) {
+	for (mut orb_transform, magnetized) in orb_query.iter_mut() {
+		// Magnetized orbs fly to the magnet's player (or the nearest one if they're gone)
+		if let Some(magnetized) = magnetized {
+			let target = player_query
+				.get(magnetized.player)
+				.map(|(transform, _)| transform.translation)
+				.ok()
+				.or_else(|| {
+					crate::movement::nearest_translation(
+						orb_transform.translation,
+						player_query.iter().map(|(transform, _)| transform),
+					)
+				});
+			let Some(target) = target else {
+				continue;
+			};
+			let direction = (target - orb_transform.translation).normalize_or_zero();
+			orb_transform.translation +=
+				direction * crate::constants::XP_MAGNET_PULL_SPEED * time.delta_secs();
+			continue;
+		}
+
+		// Attract orbs toward the nearest player whose pickup radius reaches them
+		let Some(player_position) =
+			nearest_player_in_radius(orb_transform.translation, 1.0, player_query.iter())
+		else {
+			continue;
+		};
+
+		let direction = (player_position - orb_transform.translation).normalize();
+		orb_transform.translation +=
+			direction * crate::constants::XP_ORB_MOVEMENT_SPEED * time.delta_secs();
+	}
+}
+
+// Position of the nearest player whose pickup radius, scaled by `radius_scale`, covers the point
+pub(crate) fn nearest_player_in_radius<'a>(
+	position: Vec3,
+	radius_scale: f32,
+	players: impl Iterator<Item = (&'a Transform, &'a crate::player::Player)>,
+) -> Option<Vec3> {
+	players
+		.map(|(transform, player)| {
+			(
+				transform.translation,
+				transform.translation.distance(position),
+				player.pickup_radius * radius_scale,
+			)
+		})
+		.filter(|(_, distance, radius)| distance < radius)
+		.min_by(|(_, a, _), (_, b, _)| a.partial_cmp(b).unwrap())
+		.map(|(translation, _, _)| translation)
+}
+
+type CollectingPlayerQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'static Transform,
+		&'static crate::player::Player,
+		&'static mut PlayerExperience,
+	),
+>;
+
 fn collect_experience(
 	mut commands: Commands,
-	mut player_xp: ResMut<PlayerExperience>,
 	orb_query: Query<(Entity, &Transform, &ExperienceOrb)>,
-	player_query: Query<&Transform, With<crate::player::Player>>,
+	mut player_query: CollectingPlayerQuery,
+	game_config: Option<Res<crate::GameConfig>>,
+	config_assets: Res<Assets<crate::GameConfigData>>,
 ) {
-	if let Ok(player_transform) = player_query.single() {
-		for (entity, orb_transform, orb) in orb_query.iter() {
-			let distance = player_transform
-				.translation
-				.distance(orb_transform.translation);
-
-			if distance < crate::constants::XP_ORB_COLLECTION_RANGE {
-				player_xp.current_xp += orb.value;
-				commands.entity(entity).despawn();
+	let shared_xp = game_config
+		.and_then(|config| config_assets.get(&config.config_handle))
+		.is_some_and(|config_data| config_data.coop.shared_xp);
+
+	for (entity, orb_transform, orb) in orb_query.iter() {
+		let Some(collector) = player_query
+			.iter()
+			.find(|(_, player_transform, _, _)| {
+				player_transform
+					.translation
+					.distance(orb_transform.translation)
+					< crate::constants::XP_ORB_COLLECTION_RANGE
+			})
+			.map(|(player_entity, _, _, _)| player_entity)
+		else {
+			continue;
+		};
+
+		// Each player's own multiplier applies, including to shared XP
+		for (player_entity, _, player, mut player_xp) in player_query.iter_mut() {
+			if shared_xp || player_entity == collector {
+				player_xp.current_xp += (orb.value as f32 * player.xp_multiplier).round() as u32;
 			}
 		}
+		commands.entity(entity).despawn();
 	}
 }
 
+type LevelingPlayerQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'static mut PlayerExperience,
+		&'static mut crate::player::Player,
+		&'static mut crate::behaviors::Damageable,
+		&'static mut crate::behaviors::PlayerEnergy,
+		&'static mut crate::player::PlayerStats,
+		&'static mut crate::powerups::PowerupActions,
+	),
+>;
+
 fn check_level_up(
-	mut player_xp: ResMut<PlayerExperience>,
-	mut player_query: Query<&mut crate::player::Player>,
+	mut player_query: LevelingPlayerQuery,
 	mut level_up_events: MessageWriter<LevelUpEvent>,
+	game_config: Option<Res<crate::GameConfig>>,
+	config_assets: Res<Assets<crate::GameConfigData>>,
 ) {
-	if player_xp.current_xp >= player_xp.xp_to_next_level {
-		player_xp.current_xp -= player_xp.xp_to_next_level;
-		player_xp.xp_to_next_level =
-			(player_xp.xp_to_next_level as f32 * crate::constants::XP_LEVEL_SCALING) as u32;
+	let Some(config_data) = game_config
+		.as_ref()
+		.and_then(|config| config_assets.get(&config.config_handle))
+	else {
+		return;
+	};
 
-		if let Ok(mut player) = player_query.single_mut() {
+	for (
+		entity,
+		mut player_xp,
+		mut player,
+		mut damageable,
+		mut energy,
+		mut stats,
+		mut powerup_actions,
+	) in player_query.iter_mut()
+	{
+		let xp_to_next_level = config_data.xp_curve.xp_to_next_level(player.level);
+		if player_xp.xp_to_next_level != xp_to_next_level {
+			player_xp.xp_to_next_level = xp_to_next_level;
+		}
+
+		// A big orb can be worth several levels; each one queues its own powerup menu
+		while player_xp.current_xp >= player_xp.xp_to_next_level {
+			player_xp.current_xp -= player_xp.xp_to_next_level;
 			player.level += 1;
-			level_up_events.write(LevelUpEvent);
+			let level = player.level;
+			player_xp.xp_to_next_level = config_data.xp_curve.xp_to_next_level(level);
+
+			for reward in config_data
+				.level_rewards
+				.iter()
+				.filter(|reward| reward.level == level)
+			{
+				match &reward.reward {
+					LevelRewardType::ExtraOptions(count) => {
+						player.powerup_options += count;
+					}
+					LevelRewardType::Stat(stat, value) => {
+						crate::powerups::application::apply_stat(
+							stat,
+							*value,
+							(&mut player, &mut damageable, &mut energy, &mut stats),
+						);
+					}
+					LevelRewardType::Rerolls(count) => {
+						powerup_actions.rerolls += count;
+					}
+					LevelRewardType::Skips(count) => {
+						powerup_actions.skips += count;
+					}
+					LevelRewardType::Banishes(count) => {
+						powerup_actions.banishes += count;
+					}
+				}
+			}
+
+			level_up_events.write(LevelUpEvent {
+				player: entity,
+				level,
+			});
 		}
 	}
 }
@@ -0,0 +1,581 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+use serde::{Deserialize, Serialize};
+
+mod rebind;
+
+pub use rebind::RebindMenu;
+
+pub struct InputMappingPlugin;
+
+impl Plugin for InputMappingPlugin {
+	fn build(&self, app: &mut App) {
+		app.insert_resource(load_input_bindings())
+			.init_resource::<RebindMenu>()
+			.add_systems(
+				Update,
+				(
+					rebind::toggle_rebind_menu,
+					rebind::handle_rebind_input,
+					rebind::update_rebind_rows,
+				)
+					.chain(),
+			)
+			.add_systems(Update, assign_gamepads);
+	}
+}
+
+/// Everything the player can bind. Weapon actions follow the order of `weapon_slots` in
+/// game_config.ron, starting at 0.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum InputAction {
+	MoveLeft,
+	MoveRight,
+	MoveUp,
+	MoveDown,
+	Jump,
+	Dash,
+	Charge,
+	Weapon(usize),
+	MenuUp,
+	MenuDown,
+	MenuConfirm,
+	Join, // Drops a new co-op player in from a keyboard or gamepad no one is using
+}
+
+impl InputAction {
+	pub fn label(&self, loadout: Option<&crate::weapons::WeaponSlotLoadout>) -> String {
+		match self {
+			InputAction::MoveLeft => "Move Left".to_string(),
+			InputAction::MoveRight => "Move Right".to_string(),
+			InputAction::MoveUp => "Move Up / Aim Up".to_string(),
+			InputAction::MoveDown => "Move Down / Drop".to_string(),
+			InputAction::Jump => "Jump".to_string(),
+			InputAction::Dash => "Dash".to_string(),
+			InputAction::Charge => "Charge Energy".to_string(),
+			InputAction::Weapon(index) => loadout
+				.and_then(|loadout| loadout.slots.get(*index))
+				.map(|slot| format!("Weapon: {}", slot.name))
+				.unwrap_or_else(|| format!("Weapon {}", index + 1)),
+			InputAction::MenuUp => "Menu Up".to_string(),
+			InputAction::MenuDown => "Menu Down".to_string(),
+			InputAction::MenuConfirm => "Menu Confirm".to_string(),
+			InputAction::Join => "Join Game".to_string(),
+		}
+	}
+
+	// Menu and gameplay actions never fire together, so they may share inputs
+	pub fn is_menu(&self) -> bool {
+		matches!(
+			self,
+			InputAction::MenuUp | InputAction::MenuDown | InputAction::MenuConfirm
+		)
+	}
+}
+
+#[derive(Clone)]
+pub struct ActionBinding {
+	pub action: InputAction,
+	pub keys: Vec<KeyCode>,
+	pub buttons: Vec<GamepadButton>,
+}
+
+impl ActionBinding {
+	fn pressed<'a>(
+		&self,
+		keyboard: Option<&ButtonInput<KeyCode>>,
+		mut gamepads: impl Iterator<Item = &'a Gamepad>,
+	) -> bool {
+		keyboard.is_some_and(|keyboard| keyboard.any_pressed(self.keys.iter().copied()))
+			|| gamepads.any(|gamepad| gamepad.any_pressed(self.buttons.iter().copied()))
+	}
+
+	fn just_pressed<'a>(
+		&self,
+		keyboard: Option<&ButtonInput<KeyCode>>,
+		mut gamepads: impl Iterator<Item = &'a Gamepad>,
+	) -> bool {
+		keyboard.is_some_and(|keyboard| keyboard.any_just_pressed(self.keys.iter().copied()))
+			|| gamepads.any(|gamepad| gamepad.any_just_pressed(self.buttons.iter().copied()))
+	}
+}
+
+/// Current key and gamepad bindings for every action, in rebinding screen order.
+#[derive(Resource, Clone)]
+pub struct InputBindings {
+	pub bindings: Vec<ActionBinding>,
+}
+
+impl InputBindings {
+	pub fn get(&self, action: InputAction) -> Option<&ActionBinding> {
+		self.bindings
+			.iter()
+			.find(|binding| binding.action == action)
+	}
+
+	// Replace the binding for an action, or add it if it's new
+	pub fn set(&mut self, binding: ActionBinding) {
+		match self
+			.bindings
+			.iter_mut()
+			.find(|existing| existing.action == binding.action)
+		{
+			Some(existing) => *existing = binding,
+			None => self.bindings.push(binding),
+		}
+	}
+
+	/// Short binding label for the HUD, e.g. "Q/West".
+	pub fn label(&self, action: InputAction) -> String {
+		let Some(binding) = self.get(action) else {
+			return "Unbound".to_string();
+		};
+
+		let parts: Vec<&str> = binding
+			.keys
+			.iter()
+			.filter_map(|key| key_name(*key))
+			.map(|key| {
+				key.strip_prefix("Key")
+					.or_else(|| key.strip_prefix("Digit"))
+					.unwrap_or(key)
+			})
+			.chain(
+				binding
+					.buttons
+					.iter()
+					.filter_map(|button| button_name(*button)),
+			)
+			.collect();
+
+		if parts.is_empty() {
+			"Unbound".to_string()
+		} else {
+			parts.join("/")
+		}
+	}
+}
+
+impl Default for InputBindings {
+	fn default() -> Self {
+		use InputAction::*;
+
+		let binding = |action, keys: &[KeyCode], buttons: &[GamepadButton]| ActionBinding {
+			action,
+			keys: keys.to_vec(),
+			buttons: buttons.to_vec(),
+		};
+
+		// Weapon bindings are added from game_config.ron once the slot loadout is known
+		Self {
+			bindings: vec![
+				binding(
+					MoveLeft,
+					&[KeyCode::KeyA, KeyCode::ArrowLeft],
+					&[GamepadButton::DPadLeft],
+				),
+				binding(
+					MoveRight,
+					&[KeyCode::KeyD, KeyCode::ArrowRight],
+					&[GamepadButton::DPadRight],
+				),
+				binding(
+					MoveUp,
+					&[KeyCode::KeyW, KeyCode::ArrowUp],
+					&[GamepadButton::DPadUp],
+				),
+				binding(
+					MoveDown,
+					&[KeyCode::KeyS, KeyCode::ArrowDown],
+					&[GamepadButton::DPadDown],
+				),
+				binding(
+					Jump,
+					&[KeyCode::Space, KeyCode::KeyW],
+					&[GamepadButton::South],
+				),
+				binding(
+					Dash,
+					&[KeyCode::ShiftLeft, KeyCode::ShiftRight],
+					&[GamepadButton::RightTrigger],
+				),
+				binding(Charge, &[KeyCode::KeyF], &[GamepadButton::North]),
+				binding(
+					MenuUp,
+					&[KeyCode::ArrowUp, KeyCode::KeyW],
+					&[GamepadButton::DPadUp],
+				),
+				binding(
+					MenuDown,
+					&[KeyCode::ArrowDown, KeyCode::KeyS],
+					&[GamepadButton::DPadDown],
+				),
+				binding(
+					MenuConfirm,
+					&[KeyCode::Enter, KeyCode::Space],
+					&[GamepadButton::South],
+				),
+				binding(Join, &[KeyCode::Enter], &[GamepadButton::Start]),
+			],
+		}
+	}
+}
+
+/// Devices driving one player. Player 1 starts on the keyboard and picks up the first
+/// gamepad used; each other player owns exactly one gamepad.
+#[derive(Component, Clone, Copy)]
+pub struct PlayerController {
+	pub index: usize, // 0 for player 1
+	pub keyboard: bool,
+	pub gamepad: Option<Entity>,
+}
+
+/// Action-based view of keyboard and gamepad input, per player. Reports nothing while the
+/// rebinding screen is open.
+#[derive(SystemParam)]
+pub struct ActionInput<'w, 's> {
+	keyboard: Res<'w, ButtonInput<KeyCode>>,
+	gamepads: Query<'w, 's, &'static Gamepad>,
+	bindings: Res<'w, InputBindings>,
+	rebind_menu: Res<'w, RebindMenu>,
+}
+
+impl ActionInput<'_, '_> {
+	pub fn pressed(&self, controller: &PlayerController, action: InputAction) -> bool {
+		!self.rebind_menu.open && self.binding_pressed(controller, action)
+	}
+
+	pub fn just_pressed(&self, controller: &PlayerController, action: InputAction) -> bool {
+		!self.rebind_menu.open
+			&& self.bindings.get(action).is_some_and(|binding| {
+				binding.just_pressed(
+					self.keyboard(controller),
+					self.gamepad(controller).into_iter(),
+				)
+			})
+	}
+
+	/// Menu input from the keyboard or any gamepad, for screens shown before players exist.
+	pub fn menu_just_pressed(&self, action: InputAction) -> bool {
+		!self.rebind_menu.open
+			&& self.bindings.get(action).is_some_and(|binding| {
+				binding.just_pressed(Some(&self.keyboard), self.gamepads.iter())
+			})
+	}
+
+	/// Input from the keyboard alone, whoever it belongs to.
+	pub fn keyboard_just_pressed(&self, action: InputAction) -> bool {
+		!self.rebind_menu.open
+			&& self.bindings.get(action).is_some_and(|binding| {
+				binding.just_pressed(Some(&self.keyboard), std::iter::empty())
+			})
+	}
+
+	/// Input from one gamepad, whoever it belongs to.
+	pub fn gamepad_just_pressed(&self, gamepad: Entity, action: InputAction) -> bool {
+		!self.rebind_menu.open
+			&& self.bindings.get(action).is_some_and(|binding| {
+				binding.just_pressed(None, self.gamepads.get(gamepad).into_iter())
+			})
+	}
+
+	/// Horizontal movement in -1..=1. Bound keys/buttons win over the left stick.
+	pub fn move_x(&self, controller: &PlayerController) -> f32 {
+		self.axis(
+			controller,
+			InputAction::MoveLeft,
+			InputAction::MoveRight,
+			GamepadAxis::LeftStickX,
+		)
+	}
+
+	/// Vertical movement in -1..=1, positive is up.
+	pub fn move_y(&self, controller: &PlayerController) -> f32 {
+		self.axis(
+			controller,
+			InputAction::MoveDown,
+			InputAction::MoveUp,
+			GamepadAxis::LeftStickY,
+		)
+	}
+
+	fn axis(
+		&self,
+		controller: &PlayerController,
+		negative: InputAction,
+		positive: InputAction,
+		stick: GamepadAxis,
+	) -> f32 {
+		if self.rebind_menu.open {
+			return 0.0;
+		}
+
+		let digital = self.binding_pressed(controller, positive) as i32
+			- self.binding_pressed(controller, negative) as i32;
+		if digital != 0 {
+			return digital as f32;
+		}
+
+		self.gamepad(controller)
+			.and_then(|gamepad| gamepad.get(stick))
+			.filter(|value| value.abs() > crate::constants::GAMEPAD_DEADZONE)
+			.unwrap_or(0.0)
+	}
+
+	fn binding_pressed(&self, controller: &PlayerController, action: InputAction) -> bool {
+		self.bindings.get(action).is_some_and(|binding| {
+			binding.pressed(
+				self.keyboard(controller),
+				self.gamepad(controller).into_iter(),
+			)
+		})
+	}
+
+	fn keyboard(&self, controller: &PlayerController) -> Option<&ButtonInput<KeyCode>> {
+		controller.keyboard.then_some(&*self.keyboard)
+	}
+
+	fn gamepad(&self, controller: &PlayerController) -> Option<&Gamepad> {
+		controller
+			.gamepad
+			.and_then(|gamepad| self.gamepads.get(gamepad).ok())
+	}
+}
+
+// Release gamepads that were disconnected, and give player 1 the first free gamepad that
+// presses anything other than Join (Join on a free gamepad drops in a new player instead)
+fn assign_gamepads(
+	mut controllers: Query<&mut PlayerController>,
+	gamepads: Query<(Entity, &Gamepad)>,
+	bindings: Res<InputBindings>,
+) {
+	let join_buttons = bindings
+		.get(InputAction::Join)
+		.map(|binding| binding.buttons.as_slice())
+		.unwrap_or_default();
+
+	for mut controller in controllers.iter_mut() {
+		if controller
+			.gamepad
+			.is_some_and(|gamepad| !gamepads.contains(gamepad))
+		{
+			controller.gamepad = None;
+		}
+	}
+
+	let Some(gamepad) = gamepads
+		.iter()
+		.filter(|(_, gamepad)| {
+			gamepad
+				.get_just_pressed()
+				.any(|button| !join_buttons.contains(button))
+		})
+		.map(|(entity, _)| entity)
+		.find(|entity| {
+			!controllers
+				.iter()
+				.any(|controller| controller.gamepad == Some(*entity))
+		})
+	else {
+		return;
+	};
+
+	if let Some(mut controller) = controllers
+		.iter_mut()
+		.find(|controller| controller.keyboard && controller.gamepad.is_none())
+	{
+		controller.gamepad = Some(gamepad);
+	}
+}
+
+// ============ Persistence ============
+
+#[derive(Serialize, Deserialize)]
+struct SavedBinding {
+	action: InputAction,
+	keys: Vec<String>,
+	buttons: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedBindings {
+	bindings: Vec<SavedBinding>,
+}
+
+// Defaults, overridden by whatever was saved from the rebinding screen
+fn load_input_bindings() -> InputBindings {
+	let mut bindings = InputBindings::default();
+
+	let path = crate::constants::INPUT_BINDINGS_PATH;
+	let Ok(contents) = std::fs::read_to_string(path) else {
+		return bindings;
+	};
+
+	let saved = match ron::from_str::<SavedBindings>(&contents) {
+		Ok(saved) => saved,
+		Err(err) => {
+			warn!("Ignoring {}: {}", path, err);
+			return bindings;
+		}
+	};
+
+	for binding in saved.bindings {
+		bindings.set(ActionBinding {
+			action: binding.action,
+			keys: binding
+				.keys
+				.iter()
+				.filter_map(|name| parse_key_code(name))
+				.collect(),
+			buttons: binding
+				.buttons
+				.iter()
+				.filter_map(|name| parse_gamepad_button(name))
+				.collect(),
+		});
+	}
+
+	bindings
+}
+
+fn save_input_bindings(bindings: &InputBindings) {
+	let saved = SavedBindings {
+		bindings: bindings
+			.bindings
+			.iter()
+			.map(|binding| SavedBinding {
+				action: binding.action,
+				keys: binding
+					.keys
+					.iter()
+					.filter_map(|key| key_name(*key))
+					.map(str::to_string)
+					.collect(),
+				buttons: binding
+					.buttons
+					.iter()
+					.filter_map(|button| button_name(*button))
+					.map(str::to_string)
+					.collect(),
+			})
+			.collect(),
+	};
+
+	let path = crate::constants::INPUT_BINDINGS_PATH;
+	let result = ron::ser::to_string_pretty(&saved, ron::ser::PrettyConfig::default())
+		.map_err(|err| err.to_string())
+		.and_then(|contents| std::fs::write(path, contents).map_err(|err| err.to_string()));
+
+	if let Err(err) = result {
+		warn!("Failed to save {}: {}", path, err);
+	}
+}
+
+// ============ Key and Button Names ============
+
+// Key names follow bevy's KeyCode variants
+const KEY_NAMES: &[(&str, KeyCode)] = &[
+	("KeyA", KeyCode::KeyA),
+	("KeyB", KeyCode::KeyB),
+	("KeyC", KeyCode::KeyC),
+	("KeyD", KeyCode::KeyD),
+	("KeyE", KeyCode::KeyE),
+	("KeyF", KeyCode::KeyF),
+	("KeyG", KeyCode::KeyG),
+	("KeyH", KeyCode::KeyH),
+	("KeyI", KeyCode::KeyI),
+	("KeyJ", KeyCode::KeyJ),
+	("KeyK", KeyCode::KeyK),
+	("KeyL", KeyCode::KeyL),
+	("KeyM", KeyCode::KeyM),
+	("KeyN", KeyCode::KeyN),
+	("KeyO", KeyCode::KeyO),
+	("KeyP", KeyCode::KeyP),
+	("KeyQ", KeyCode::KeyQ),
+	("KeyR", KeyCode::KeyR),
+	("KeyS", KeyCode::KeyS),
+	("KeyT", KeyCode::KeyT),
+	("KeyU", KeyCode::KeyU),
+	("KeyV", KeyCode::KeyV),
+	("KeyW", KeyCode::KeyW),
+	("KeyX", KeyCode::KeyX),
+	("KeyY", KeyCode::KeyY),
+	("KeyZ", KeyCode::KeyZ),
+	("Digit0", KeyCode::Digit0),
+	("Digit1", KeyCode::Digit1),
+	("Digit2", KeyCode::Digit2),
+	("Digit3", KeyCode::Digit3),
+	("Digit4", KeyCode::Digit4),
+	("Digit5", KeyCode::Digit5),
+	("Digit6", KeyCode::Digit6),
+	("Digit7", KeyCode::Digit7),
+	("Digit8", KeyCode::Digit8),
+	("Digit9", KeyCode::Digit9),
+	("Space", KeyCode::Space),
+	("Tab", KeyCode::Tab),
+	("Enter", KeyCode::Enter),
+	("ShiftLeft", KeyCode::ShiftLeft),
+	("ShiftRight", KeyCode::ShiftRight),
+	("ControlLeft", KeyCode::ControlLeft),
+	("ControlRight", KeyCode::ControlRight),
+	("AltLeft", KeyCode::AltLeft),
+	("AltRight", KeyCode::AltRight),
+	("ArrowUp", KeyCode::ArrowUp),
+	("ArrowDown", KeyCode::ArrowDown),
+	("ArrowLeft", KeyCode::ArrowLeft),
+	("ArrowRight", KeyCode::ArrowRight),
+	("Comma", KeyCode::Comma),
+	("Period", KeyCode::Period),
+	("Slash", KeyCode::Slash),
+	("Semicolon", KeyCode::Semicolon),
+	("Quote", KeyCode::Quote),
+	("BracketLeft", KeyCode::BracketLeft),
+	("BracketRight", KeyCode::BracketRight),
+	("Minus", KeyCode::Minus),
+	("Equal", KeyCode::Equal),
+];
+
+// Button names follow bevy's GamepadButton variants
+const BUTTON_NAMES: &[(&str, GamepadButton)] = &[
+	("South", GamepadButton::South),
+	("East", GamepadButton::East),
+	("North", GamepadButton::North),
+	("West", GamepadButton::West),
+	("LeftTrigger", GamepadButton::LeftTrigger),
+	("LeftTrigger2", GamepadButton::LeftTrigger2),
+	("RightTrigger", GamepadButton::RightTrigger),
+	("RightTrigger2", GamepadButton::RightTrigger2),
+	("LeftThumb", GamepadButton::LeftThumb),
+	("RightThumb", GamepadButton::RightThumb),
+	("DPadUp", GamepadButton::DPadUp),
+	("DPadDown", GamepadButton::DPadDown),
+	("DPadLeft", GamepadButton::DPadLeft),
+	("DPadRight", GamepadButton::DPadRight),
+];
+
+pub fn parse_key_code(name: &str) -> Option<KeyCode> {
+	KEY_NAMES
+		.iter()
+		.find(|(key_name, _)| *key_name == name)
+		.map(|(_, key)| *key)
+}
+
+pub fn parse_gamepad_button(name: &str) -> Option<GamepadButton> {
+	BUTTON_NAMES
+		.iter()
+		.find(|(button_name, _)| *button_name == name)
+		.map(|(_, button)| *button)
+}
+
+fn key_name(key: KeyCode) -> Option<&'static str> {
+	KEY_NAMES
+		.iter()
+		.find(|(_, named_key)| *named_key == key)
+		.map(|(name, _)| *name)
+}
+
+fn button_name(button: GamepadButton) -> Option<&'static str> {
+	BUTTON_NAMES
+		.iter()
+		.find(|(_, named_button)| *named_button == button)
+		.map(|(name, _)| *name)
+}
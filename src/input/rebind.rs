@@ -0,0 +1,306 @@
+use bevy::prelude::*;
+
+/// State of the controls screen (F1 / Select), where any action can be rebound.
+#[derive(Resource, Default)]
+pub struct RebindMenu {
+	pub open: bool,
+	pub selected: usize,
+	pub capturing: bool, // Waiting for the new key or button for the selected action
+	pub slot: usize,     // Which of the selected action's keys or buttons a capture replaces
+}
+
+#[derive(Component)]
+pub(super) struct RebindMenuContainer;
+
+#[derive(Component)]
+pub(super) struct RebindRow(usize);
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn toggle_rebind_menu(
+	mut commands: Commands,
+	keyboard: Res<ButtonInput<KeyCode>>,
+	gamepads: Query<&Gamepad>,
+	mut menu: ResMut<RebindMenu>,
+	mut time: ResMut<Time<Virtual>>,
+	bindings: Res<super::InputBindings>,
+	powerup_state: Res<crate::powerups::PowerupState>,
+	container_query: Query<Entity, With<RebindMenuContainer>>,
+) {
+	// Fixed keys, so the screen can't be locked out by a bad binding
+	let toggle_pressed = keyboard.just_pressed(KeyCode::F1)
+		|| gamepads
+			.iter()
+			.any(|g| g.just_pressed(GamepadButton::Select));
+
+	if !menu.open {
+		// Don't open on top of the level-up menu
+		if toggle_pressed && !powerup_state.showing {
+			menu.open = true;
+			menu.selected = 0;
+			menu.slot = 0;
+			menu.capturing = false;
+			time.pause();
+			spawn_rebind_menu(&mut commands, &bindings);
+		}
+		return;
+	}
+
+	// Escape cancels a capture first (see handle_rebind_input)
+	if !menu.capturing && (toggle_pressed || keyboard.just_pressed(KeyCode::Escape)) {
+		menu.open = false;
+		time.unpause();
+		for entity in container_query.iter() {
+			commands.entity(entity).despawn();
+		}
+	}
+}
+
+// Navigate the action list, or capture the new binding for the selected action
+// (gameplay ActionInput is blocked while the menu is open, so bindings are read directly)
+pub(super) fn handle_rebind_input(
+	keyboard: Res<ButtonInput<KeyCode>>,
+	gamepads: Query<&Gamepad>,
+	mut menu: ResMut<RebindMenu>,
+	mut bindings: ResMut<super::InputBindings>,
+) {
+	if !menu.open {
+		return;
+	}
+
+	if !menu.capturing {
+		let menu_pressed = |action| {
+			bindings
+				.get(action)
+				.is_some_and(|binding| binding.just_pressed(Some(&keyboard), gamepads.iter()))
+		};
+		let up = menu_pressed(super::InputAction::MenuUp);
+		let down = menu_pressed(super::InputAction::MenuDown);
+		let confirm = menu_pressed(super::InputAction::MenuConfirm);
+		let left = menu_pressed(super::InputAction::MoveLeft);
+		let right = menu_pressed(super::InputAction::MoveRight);
+
+		let count = bindings.bindings.len();
+		if up {
+			menu.selected = (menu.selected + count - 1) % count;
+		}
+		if down {
+			menu.selected = (menu.selected + 1) % count;
+		}
+		const SLOTS: usize = crate::constants::REBIND_SLOTS;
+		if left {
+			menu.slot = (menu.slot + SLOTS - 1) % SLOTS;
+		}
+		if right {
+			menu.slot = (menu.slot + 1) % SLOTS;
+		}
+		if confirm {
+			menu.capturing = true;
+		}
+		return;
+	}
+
+	if keyboard.just_pressed(KeyCode::Escape) {
+		menu.capturing = false;
+		return;
+	}
+
+	// Only named keys and buttons can be saved, so only those are accepted
+	let key = super::KEY_NAMES
+		.iter()
+		.map(|(_, key)| *key)
+		.find(|key| keyboard.just_pressed(*key));
+	let button = super::BUTTON_NAMES
+		.iter()
+		.map(|(_, button)| *button)
+		.find(|button| gamepads.iter().any(|g| g.just_pressed(*button)));
+
+	if key.is_none() && button.is_none() {
+		return;
+	}
+
+	// A key replaces the selected keyboard slot, a button the gamepad one
+	let Some(action) = bindings
+		.bindings
+		.get(menu.selected)
+		.map(|binding| binding.action)
+	else {
+		menu.capturing = false;
+		return;
+	};
+	if let Some(key) = key {
+		rebind_slot(&mut bindings, action, menu.slot, key, |binding| {
+			&mut binding.keys
+		});
+	} else if let Some(button) = button {
+		rebind_slot(&mut bindings, action, menu.slot, button, |binding| {
+			&mut binding.buttons
+		});
+	}
+	super::save_input_bindings(&bindings);
+	menu.capturing = false;
+}
+
+// Puts the input in one slot of the action. Another action it would clash with (both menu
+// or both gameplay) gets the input this slot held before, or loses it if the slot was empty
+fn rebind_slot<T: Copy + PartialEq>(
+	bindings: &mut super::InputBindings,
+	action: super::InputAction,
+	slot: usize,
+	input: T,
+	inputs: impl Fn(&mut super::ActionBinding) -> &mut Vec<T>,
+) {
+	let Some(binding) = bindings
+		.bindings
+		.iter_mut()
+		.find(|binding| binding.action == action)
+	else {
+		return;
+	};
+	let slots = inputs(binding);
+
+	// Already bound to this action, just move it into the slot
+	if let Some(position) = slots.iter().position(|bound| *bound == input) {
+		if slot < slots.len() {
+			slots.swap(position, slot);
+		}
+		return;
+	}
+
+	let previous = match slots.get_mut(slot) {
+		Some(bound) => Some(std::mem::replace(bound, input)),
+		None => {
+			slots.push(input);
+			None
+		}
+	};
+
+	for other in bindings
+		.bindings
+		.iter_mut()
+		.filter(|other| other.action != action && other.action.is_menu() == action.is_menu())
+	{
+		let slots = inputs(other);
+		let Some(position) = slots.iter().position(|bound| *bound == input) else {
+			continue;
+		};
+		match previous {
+			Some(previous) if !slots.contains(&previous) => slots[position] = previous,
+			_ => {
+				slots.remove(position);
+			}
+		}
+	}
+}
+
+pub(super) fn update_rebind_rows(
+	menu: Res<RebindMenu>,
+	bindings: Res<super::InputBindings>,
+	loadout: Option<Res<crate::weapons::WeaponSlotLoadout>>,
+	mut rows: Query<(&RebindRow, &mut Text, &mut BackgroundColor)>,
+) {
+	if !menu.open {
+		return;
+	}
+
+	for (row, mut text, mut color) in rows.iter_mut() {
+		let Some(binding) = bindings.bindings.get(row.0) else {
+			continue;
+		};
+		let selected = row.0 == menu.selected;
+
+		let value = if selected && menu.capturing {
+			format!("press a key or button for slot {}...", menu.slot + 1)
+		} else if selected {
+			format!(
+				"{} [slot {}]",
+				bindings.label(binding.action),
+				menu.slot + 1
+			)
+		} else {
+			bindings.label(binding.action)
+		};
+		let label = format!("{}: {}", binding.action.label(loadout.as_deref()), value);
+		if **text != label {
+			**text = label;
+		}
+
+		let row_color = if selected {
+			crate::constants::POWERUP_COLOR_SELECTED
+		} else {
+			crate::constants::POWERUP_COLOR_NORMAL
+		};
+		if color.0 != row_color {
+			color.0 = row_color;
+		}
+	}
+}
+
+fn spawn_rebind_menu(commands: &mut Commands, bindings: &super::InputBindings) {
+	use crate::constants::*;
+
+	let container = commands
+		.spawn((
+			Node {
+				width: Val::Percent(100.0),
+				height: Val::Percent(100.0),
+				position_type: PositionType::Absolute,
+				flex_direction: FlexDirection::Column,
+				justify_content: JustifyContent::Center,
+				align_items: AlignItems::Center,
+				row_gap: Val::Px(REBIND_ROW_GAP),
+				..default()
+			},
+			BackgroundColor(Color::srgba(0.0, 0.0, 0.0, REBIND_OVERLAY_ALPHA)),
+			ZIndex(20),
+			RebindMenuContainer,
+		))
+		.id();
+
+	let title = commands
+		.spawn((
+			Text::new("Controls"),
+			TextFont {
+				font_size: UI_FONT_SIZE_LARGE,
+				..default()
+			},
+			TextColor(Color::srgb(0.9, 0.9, 0.3)),
+		))
+		.id();
+	let hint = commands
+		.spawn((
+			Text::new("Up/Down to select, Left/Right to pick a slot, Confirm to rebind, Esc to cancel, F1 to close"),
+			TextFont {
+				font_size: UI_FONT_SIZE_SMALL,
+				..default()
+			},
+			TextColor(Color::srgb(0.7, 0.7, 0.7)),
+			Node {
+				margin: UiRect::bottom(Val::Px(REBIND_ROW_GAP * 2.0)),
+				..default()
+			},
+		))
+		.id();
+	commands.entity(container).add_children(&[title, hint]);
+
+	// Row text is filled in by update_rebind_rows
+	for index in 0..bindings.bindings.len() {
+		let row = commands
+			.spawn((
+				Text::new(""),
+				TextFont {
+					font_size: UI_FONT_SIZE_SMALL,
+					..default()
+				},
+				TextColor(Color::WHITE),
+				Node {
+					width: Val::Px(REBIND_ROW_WIDTH),
+					padding: UiRect::axes(Val::Px(10.0), Val::Px(2.0)),
+					..default()
+				},
+				BackgroundColor(POWERUP_COLOR_NORMAL),
+				RebindRow(index),
+			))
+			.id();
+		commands.entity(container).add_child(row);
+	}
+}
@@ -8,22 +8,30 @@ use bevy::{
 use serde::Deserialize;
 
 mod behaviors;
+mod character;
 mod combat;
 mod constants;
 mod enemy;
 mod experience;
+mod input;
 mod movement;
 mod physics;
+mod pickups;
+mod platforms;
 mod player;
 mod powerups;
 mod validation;
 mod weapons;
 
+use character::CharacterPlugin;
 use combat::CombatPlugin;
 use enemy::EnemyPlugin;
 use experience::ExperiencePlugin;
+use input::InputMappingPlugin;
 use movement::MovementPlugin;
 use physics::PhysicsPlugin;
+use pickups::PickupsPlugin;
+use platforms::PlatformsPlugin;
 use player::PlayerPlugin;
 use powerups::PowerupsPlugin;
 use validation::ValidationPlugin;
@@ -42,16 +50,29 @@ pub struct InitialWeapon {
 	pub level: u32,
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Clone, PartialEq)]
 pub enum StatType {
 	Speed,
 	JumpForce,
 	MaxHealth,
 	EnergyRegen,
 	RepulsionForce,
+	ExtraJumps,   // Value is rounded down to whole jumps
+	DashCharges,  // Value is rounded down to whole charges
+	DashCooldown, // Seconds removed from the time to recharge one dash
+	PickupRadius,
+	XpMultiplier, // Added to the multiplier, e.g. 0.1 for +10% XP
+	Might,        // Bonus damage for every weapon, e.g. 0.1 for +10%
+	Area,         // Bonus size for projectiles, hitboxes and explosions
+	Cooldown,     // Cooldown reduction for every weapon, e.g. 0.05 for -5%
+	Amount,       // Extra projectiles and summons, rounded down
+	Armor,        // Flat damage removed from every hit taken
+	Luck,         // Bonus chance for drops and rarer powerups
+	Lifesteal,    // Fraction of damage dealt returned as health
+	HealthRegen,  // Health restored per second
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Clone, PartialEq)]
 pub struct StatBoostData {
 	pub stat: StatType,
 	pub value: f32,
@@ -59,18 +80,211 @@ pub struct StatBoostData {
 	pub description: String,
 }
 
+#[derive(Deserialize, Clone, PartialEq)]
+pub enum EvolutionRequirement {
+	StatBoost(String), // Name of a stat boost the player has taken
+	Weapon(String),    // ID of another weapon the player owns
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct EvolutionRecipe {
+	pub base_weapon: String,
+	pub base_level: u32,
+	pub requires: EvolutionRequirement,
+	pub evolved_weapon: String,
+}
+
+/// Player movement tuning. Omitted fields fall back to the constants in constants.rs.
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct PlayerMovementConfig {
+	pub coyote_time: f32,         // Grace period to jump after walking off a ledge
+	pub jump_buffer_time: f32,    // How long a jump press is remembered before landing
+	pub jump_cut_multiplier: f32, // Upward velocity kept when jump is released early
+	pub extra_jumps: u32,         // Mid-air jumps before landing
+	pub wall_slide_speed: f32,    // Max fall speed while sliding down a wall
+	pub wall_jump_push: f32,      // Horizontal speed away from the wall on a wall jump
+	pub wall_jump_lock_time: f32, // Steering is ignored this long after a wall jump
+	pub dash_speed: f32,
+	pub dash_duration: f32,
+	pub dash_cooldown: f32,    // Time to recharge one dash charge
+	pub dash_energy_cost: f32, // PlayerEnergy spent per dash
+	pub dash_iframe_time: f32, // Invulnerability after starting a dash
+	pub dash_charges: u32,
+}
+
+impl Default for PlayerMovementConfig {
+	fn default() -> Self {
+		Self {
+			coyote_time: crate::constants::PLAYER_COYOTE_TIME,
+			jump_buffer_time: crate::constants::PLAYER_JUMP_BUFFER_TIME,
+			jump_cut_multiplier: crate::constants::PLAYER_JUMP_CUT_MULTIPLIER,
+			extra_jumps: crate::constants::PLAYER_DEFAULT_EXTRA_JUMPS,
+			wall_slide_speed: crate::constants::PLAYER_WALL_SLIDE_SPEED,
+			wall_jump_push: crate::constants::PLAYER_WALL_JUMP_PUSH,
+			wall_jump_lock_time: crate::constants::PLAYER_WALL_JUMP_LOCK_TIME,
+			dash_speed: crate::constants::PLAYER_DASH_SPEED,
+			dash_duration: crate::constants::PLAYER_DASH_DURATION,
+			dash_cooldown: crate::constants::PLAYER_DASH_COOLDOWN,
+			dash_energy_cost: crate::constants::PLAYER_DASH_ENERGY_COST,
+			dash_iframe_time: crate::constants::PLAYER_DASH_IFRAME_TIME,
+			dash_charges: crate::constants::PLAYER_DEFAULT_DASH_CHARGES,
+		}
+	}
+}
+
+/// Local co-op settings. Extra players join by pressing Start on an unused gamepad.
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct CoopConfig {
+	pub max_players: usize,
+	pub shared_xp: bool, // Every player gains XP from every orb instead of only the collector
+}
+
+impl Default for CoopConfig {
+	fn default() -> Self {
+		Self {
+			max_players: crate::constants::MAX_PLAYERS,
+			shared_xp: false,
+		}
+	}
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SlotActivation {
+	AlwaysOn, // Fires whenever ready
+	Toggle,   // Binding switches the slot on and off
+	Hold,     // Active while the binding is held
+	Manual,   // One attempt per press
+}
+
 #[derive(Deserialize, Clone)]
+pub struct WeaponSlotConfig {
+	pub name: String,
+	pub activation: SlotActivation,
+	#[serde(default)]
+	pub key: Option<String>, // KeyCode name, e.g. "KeyQ"
+	#[serde(default)]
+	pub gamepad_button: Option<String>, // GamepadButton name, e.g. "West"
+}
+
+// Matches the original Q/E melee and ranged toggles
+fn default_weapon_slots() -> Vec<WeaponSlotConfig> {
+	vec![
+		WeaponSlotConfig {
+			name: "Melee".to_string(),
+			activation: SlotActivation::Toggle,
+			key: Some("KeyQ".to_string()),
+			gamepad_button: Some("West".to_string()),
+		},
+		WeaponSlotConfig {
+			name: "Ranged".to_string(),
+			activation: SlotActivation::Toggle,
+			key: Some("KeyE".to_string()),
+			gamepad_button: Some("East".to_string()),
+		},
+	]
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
 pub enum PowerupDefinition {
 	Weapon(String),
 	StatBoost(StatBoostData),
+	// Offered automatically when a recipe's conditions are met, never listed in powerup_pool
+	#[serde(skip)]
+	Evolution(EvolutionRecipe),
+}
+
+impl PowerupDefinition {
+	// Weapon ID or stat boost name, as used by pool requires and excludes
+	pub fn id(&self) -> &str {
+		match self {
+			PowerupDefinition::Weapon(id) => id,
+			PowerupDefinition::StatBoost(boost) => &boost.name,
+			PowerupDefinition::Evolution(recipe) => &recipe.evolved_weapon,
+		}
+	}
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PowerupRarity {
+	#[default]
+	Common,
+	Uncommon,
+	Rare,
+	Epic,
+}
+
+impl PowerupRarity {
+	// Multiplies the entry's weight, so rarer cards come up less often
+	pub fn weight(self) -> f32 {
+		match self {
+			PowerupRarity::Common => crate::constants::POWERUP_WEIGHT_COMMON,
+			PowerupRarity::Uncommon => crate::constants::POWERUP_WEIGHT_UNCOMMON,
+			PowerupRarity::Rare => crate::constants::POWERUP_WEIGHT_RARE,
+			PowerupRarity::Epic => crate::constants::POWERUP_WEIGHT_EPIC,
+		}
+	}
+
+	pub fn color(self) -> Color {
+		match self {
+			PowerupRarity::Common => crate::constants::POWERUP_COLOR_COMMON,
+			PowerupRarity::Uncommon => crate::constants::POWERUP_COLOR_UNCOMMON,
+			PowerupRarity::Rare => crate::constants::POWERUP_COLOR_RARE,
+			PowerupRarity::Epic => crate::constants::POWERUP_COLOR_EPIC,
+		}
+	}
+}
+
+#[derive(Deserialize, Clone)]
+pub enum PowerupRequirement {
+	Powerup(String),          // Weapon ID owned or stat boost name taken
+	WeaponLevel(String, u32), // Weapon ID at this level or higher
+}
+
+fn default_powerup_weight() -> f32 {
+	crate::constants::POWERUP_DEFAULT_WEIGHT
+}
+
+/// One entry of powerup_pool. Everything but `powerup` is optional.
+#[derive(Deserialize, Clone)]
+pub struct PowerupPoolEntry {
+	pub powerup: PowerupDefinition,
+	#[serde(default = "default_powerup_weight")]
+	pub weight: f32,
+	#[serde(default)]
+	pub rarity: PowerupRarity,
+	#[serde(default)]
+	pub max_stacks: Option<u32>, // Times a stat boost can be taken, or a weapon's level cap
+	#[serde(default)]
+	pub requires: Vec<PowerupRequirement>, // All must be met before it's offered
+	#[serde(default)]
+	pub excludes: Vec<String>, // Never offered once any of these is owned or taken
 }
 
 #[derive(Asset, TypePath, Deserialize, Clone)]
 pub struct GameConfigData {
 	pub weapon_ids: Vec<String>,
 	pub enemy_ids: Vec<String>,
-	pub initial_weapons: Vec<InitialWeapon>,
-	pub powerup_pool: Vec<PowerupDefinition>,
+	pub character_ids: Vec<String>,
+	pub powerup_pool: Vec<PowerupPoolEntry>,
+	#[serde(default)]
+	pub evolutions: Vec<EvolutionRecipe>,
+	#[serde(default = "default_weapon_slots")]
+	pub weapon_slots: Vec<WeaponSlotConfig>,
+	#[serde(default)]
+	pub player_movement: PlayerMovementConfig,
+	#[serde(default)]
+	pub coop: CoopConfig,
+	#[serde(default)]
+	pub xp_curve: crate::experience::XpCurve,
+	#[serde(default)]
+	pub level_rewards: Vec<crate::experience::LevelReward>,
+	#[serde(default)]
+	pub skip_reward: crate::powerups::SkipReward,
+	#[serde(default = "crate::pickups::default_pickup_settings")]
+	pub pickups:
+		std::collections::HashMap<crate::pickups::PickupType, crate::pickups::PickupSettings>,
 }
 
 #[derive(Default)]
@@ -119,11 +333,15 @@ fn main() {
 		.init_asset::<GameConfigData>()
 		.init_asset_loader::<GameConfigLoader>()
 		.add_plugins((
+			InputMappingPlugin,
 			PhysicsPlugin,
+			PlatformsPlugin,
+			CharacterPlugin,
 			PlayerPlugin,
 			EnemyPlugin,
 			WeaponsPlugin,
 			ExperiencePlugin,
+			PickupsPlugin,
 			PowerupsPlugin,
 			CombatPlugin,
 			MovementPlugin,
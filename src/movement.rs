@@ -54,6 +54,21 @@ type MaintainDistanceQuery<'w, 's> = Query<
 	Without<crate::behaviors::InRepulsionField>,
 >;
 
+type EnemyDropQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		&'static Transform,
+		&'static crate::physics::Grounded,
+		&'static mut crate::physics::DropThrough,
+	),
+	(
+		With<crate::behaviors::EnemyTag>,
+		Without<crate::behaviors::FlyingMovement>,
+		Without<crate::behaviors::Stunned>,
+	),
+>;
+
 impl Plugin for MovementPlugin {
 	fn build(&self, app: &mut App) {
 		app.add_systems(
@@ -62,12 +77,23 @@ impl Plugin for MovementPlugin {
 				update_seek_target_entities,
 				update_zigzag_entities,
 				update_maintain_distance_entities,
+				update_enemy_platform_drops,
 			)
 				.in_set(MovementSystemSet),
 		);
 	}
 }
 
+/// Position of the transform closest to `from`, e.g. the nearest player in co-op.
+pub(crate) fn nearest_translation<'a>(
+	from: Vec3,
+	transforms: impl Iterator<Item = &'a Transform>,
+) -> Option<Vec3> {
+	transforms
+		.map(|transform| transform.translation)
+		.min_by(|a, b| a.distance(from).partial_cmp(&b.distance(from)).unwrap())
+}
+
 fn update_seek_target_entities(
 	mut seek_query: SeekQuery,
 	player_query: Query<&Transform, With<crate::behaviors::PlayerTag>>,
@@ -81,7 +107,7 @@ fn update_seek_target_entities(
 		}
 
 		let target_position = match seek.target_type {
-			TargetType::Player => player_query.single().ok().map(|t| t.translation),
+			TargetType::Player => nearest_translation(transform.translation, player_query.iter()),
 			TargetType::NearestEnemy => enemy_query
 				.iter()
 				.min_by(|(_, a), (_, b)| {
@@ -120,34 +146,38 @@ fn update_zigzag_entities(
 	>,
 	time: Res<Time<Virtual>>,
 ) {
-	if let Ok(player_transform) = player_query.single() {
-		for (transform, mut velocity, mut zigzag, is_stunned, is_flying) in zigzag_query.iter_mut() {
-			if is_stunned {
-				continue;
-			}
+	for (transform, mut velocity, mut zigzag, is_stunned, is_flying) in zigzag_query.iter_mut() {
+		if is_stunned {
+			continue;
+		}
 
-			zigzag.time += time.delta_secs();
+		// Zigzag toward the nearest player
+		let Some(player_position) = nearest_translation(transform.translation, player_query.iter())
+		else {
+			continue;
+		};
 
-			let direction_to_player = Vec2::new(
-				player_transform.translation.x - transform.translation.x,
-				player_transform.translation.y - transform.translation.y,
-			)
-			.normalize_or_zero();
+		zigzag.time += time.delta_secs();
 
-			let perpendicular = Vec2::new(-direction_to_player.y, direction_to_player.x);
+		let direction_to_player = Vec2::new(
+			player_position.x - transform.translation.x,
+			player_position.y - transform.translation.y,
+		)
+		.normalize_or_zero();
 
-			let oscillation =
-				(zigzag.time * zigzag.oscillation_speed).sin() * zigzag.oscillation_amplitude;
+		let perpendicular = Vec2::new(-direction_to_player.y, direction_to_player.x);
 
-			let final_direction =
-				(direction_to_player + perpendicular * oscillation).normalize_or_zero();
+		let oscillation =
+			(zigzag.time * zigzag.oscillation_speed).sin() * zigzag.oscillation_amplitude;
 
-			velocity.x = final_direction.x * zigzag.base_speed;
+		let final_direction =
+			(direction_to_player + perpendicular * oscillation).normalize_or_zero();
 
-			// Only set Y velocity for flying entities; grounded entities use gravity
-			if is_flying {
-				velocity.y = final_direction.y * zigzag.base_speed;
-			}
+		velocity.x = final_direction.x * zigzag.base_speed;
+
+		// Only set Y velocity for flying entities; grounded entities use gravity
+		if is_flying {
+			velocity.y = final_direction.y * zigzag.base_speed;
 		}
 	}
 }
@@ -171,7 +201,7 @@ fn update_maintain_distance_entities(
 		}
 
 		let target_position = match maintain.target_type {
-			TargetType::Player => player_query.single().ok().map(|t| t.translation),
+			TargetType::Player => nearest_translation(transform.translation, player_query.iter()),
 			TargetType::NearestEnemy => enemy_query
 				.iter()
 				.min_by(|(_, a), (_, b)| {
@@ -214,3 +244,23 @@ fn update_maintain_distance_entities(
 		}
 	}
 }
+
+// Ground enemies drop through one-way platforms to follow the nearest player below them
+fn update_enemy_platform_drops(
+	mut enemy_query: EnemyDropQuery,
+	player_query: Query<&Transform, With<crate::behaviors::PlayerTag>>,
+) {
+	for (transform, grounded, mut drop) in enemy_query.iter_mut() {
+		let Some(player_position) = nearest_translation(transform.translation, player_query.iter())
+		else {
+			return;
+		};
+
+		let player_below = transform.translation.y - player_position.y
+			> crate::constants::ENEMY_DROP_THROUGH_HEIGHT;
+
+		if grounded.0 && drop.on_one_way && player_below {
+			drop.timer = crate::constants::PLATFORM_DROP_THROUGH_TIME;
+		}
+	}
+}
@@ -12,7 +12,13 @@ impl Plugin for PhysicsPlugin {
 	fn build(&self, app: &mut App) {
 		app.add_systems(
 			Update,
-			(apply_gravity, apply_velocity, check_ground_collision)
+			(
+				apply_gravity,
+				apply_velocity,
+				check_ground_collision,
+				check_wall_collision,
+				check_projectile_platform_collisions,
+			)
 				.chain()
 				.in_set(PhysicsSet),
 		)
@@ -43,8 +49,49 @@ pub struct Ground;
 #[derive(Component)]
 pub struct Collider;
 
-type ColliderQuery<'w, 's> =
-	Query<'w, 's, (Entity, &'static Transform, &'static Sprite), With<Collider>>;
+/// Ground that can be dropped through from above (see DropThrough).
+#[derive(Component)]
+pub struct OneWay;
+
+/// Lets an entity drop through OneWay platforms: `on_one_way` is updated by ground collision,
+/// and OneWay platforms are ignored while `timer` is running.
+#[derive(Component, Default)]
+pub struct DropThrough {
+	pub on_one_way: bool,
+	pub timer: f32,
+}
+
+/// Set by ground collision each frame: whether anything is standing on this collider.
+#[derive(Component, Default)]
+pub struct Occupied(pub bool);
+
+/// Side of a wall the entity is touching: -1.0 = wall on the left, 1.0 = on the right, 0.0 = none.
+/// Entities with this component are also blocked by the sides of wall-height Ground colliders.
+#[derive(Component, Default)]
+pub struct WallContact(pub f32);
+
+type GravityQuery<'w, 's> = Query<
+	'w,
+	's,
+	(&'static mut Velocity, &'static Grounded),
+	(
+		Without<crate::behaviors::EnergyCharging>,
+		Without<crate::behaviors::Dashing>,
+	),
+>;
+type ColliderQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'static Transform,
+		&'static Sprite,
+		Has<OneWay>,
+		Option<&'static Velocity>,
+		Has<Ground>,
+	),
+	With<Collider>,
+>;
 type GroundedQuery<'w, 's> = Query<
 	'w,
 	's,
@@ -54,15 +101,59 @@ type GroundedQuery<'w, 's> = Query<
 		&'static Sprite,
 		&'static mut Velocity,
 		&'static mut Grounded,
+		Option<&'static mut DropThrough>,
+	),
+>;
+type PlatformProjectileQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'static mut Transform,
+		&'static Sprite,
+		&'static mut Velocity,
+		&'static mut crate::behaviors::ProjectilePlatformCollision,
+	),
+>;
+type PlatformQuery<'w, 's> = Query<
+	'w,
+	's,
+	(&'static Transform, &'static Sprite),
+	(
+		With<Ground>,
+		Without<crate::behaviors::ProjectilePlatformCollision>,
+	),
+>;
+type WallContactQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		&'static mut Transform,
+		&'static Sprite,
+		&'static mut Velocity,
+		&'static mut WallContact,
+	),
+	Without<Ground>,
+>;
+type WallQuery<'w, 's> = Query<
+	'w,
+	's,
+	(&'static Transform, &'static Sprite),
+	(With<Ground>, Without<OneWay>, Without<WallContact>),
+>;
+// Dashing entities pass through everything else
+type CollisionQuery<'w, 's> = Query<
+	'w,
+	's,
+	(&'static mut Transform, &'static Sprite),
+	(
+		With<Collider>,
+		Without<Ground>,
+		Without<crate::behaviors::Dashing>,
 	),
 >;
-type CollisionQuery<'w, 's> =
-	Query<'w, 's, (&'static mut Transform, &'static Sprite), (With<Collider>, Without<Ground>)>;
 
-fn apply_gravity(
-	mut query: Query<(&mut Velocity, &Grounded), Without<crate::behaviors::EnergyCharging>>,
-	time: Res<Time<Virtual>>,
-) {
+fn apply_gravity(mut query: GravityQuery, time: Res<Time<Virtual>>) {
 	for (mut velocity, grounded) in query.iter_mut() {
 		if !grounded.0 {
 			velocity.y += crate::constants::GRAVITY * time.delta_secs();
@@ -117,22 +208,41 @@ fn resolve_entity_collisions(mut query: CollisionQuery) {
 	}
 }
 
-fn check_ground_collision(mut param_set: ParamSet<(ColliderQuery, GroundedQuery)>) {
-	// First pass: collect all collider positions and sizes
-	let collider_data: Vec<(Entity, Vec3, Vec2)> = param_set
+fn check_ground_collision(
+	mut param_set: ParamSet<(ColliderQuery, GroundedQuery)>,
+	mut occupied_query: Query<&mut Occupied>,
+	time: Res<Time<Virtual>>,
+) {
+	let delta = time.delta_secs();
+
+	// First pass: collect all collider positions and sizes, plus how far moving platforms
+	// travelled this frame so their riders can be carried along
+	let collider_data: Vec<(Entity, Vec3, Vec2, bool, Vec2)> = param_set
 		.p0()
 		.iter()
-		.map(|(entity, transform, sprite)| {
-			(
-				entity,
-				transform.translation,
-				sprite.custom_size.unwrap_or(Vec2::ONE),
-			)
-		})
+		.map(
+			|(entity, transform, sprite, is_one_way, velocity, is_ground)| {
+				let carry = match velocity {
+					Some(velocity) if is_ground => Vec2::new(velocity.x, velocity.y) * delta,
+					_ => Vec2::ZERO,
+				};
+				(
+					entity,
+					transform.translation,
+					sprite.custom_size.unwrap_or(Vec2::ONE),
+					is_one_way,
+					carry,
+				)
+			},
+		)
 		.collect();
 
+	for mut occupied in occupied_query.iter_mut() {
+		occupied.0 = false;
+	}
+
 	// Second pass: detect ground collisions and update grounded entities
-	for (entity, mut entity_transform, entity_sprite, mut velocity, mut grounded) in
+	for (entity, mut entity_transform, entity_sprite, mut velocity, mut grounded, mut drop) in
 		param_set.p1().iter_mut()
 	{
 		let entity_size = entity_sprite.custom_size.unwrap_or(Vec2::ONE);
@@ -142,12 +252,26 @@ fn check_ground_collision(mut param_set: ParamSet<(ColliderQuery, GroundedQuery)
 
 		grounded.0 = false;
 
-		for (collider_entity, collider_translation, collider_size) in &collider_data {
+		let mut dropping = false;
+		if let Some(drop) = drop.as_mut() {
+			drop.timer = (drop.timer - delta).max(0.0);
+			drop.on_one_way = false;
+			dropping = drop.timer > 0.0;
+		}
+
+		for (collider_entity, collider_translation, collider_size, is_one_way, carry) in
+			&collider_data
+		{
 			// Skip self
 			if entity == *collider_entity {
 				continue;
 			}
 
+			// Fall through one-way platforms while dropping
+			if dropping && *is_one_way {
+				continue;
+			}
+
 			let collider_top = collider_translation.y + collider_size.y / 2.0;
 			let collider_left = collider_translation.x - collider_size.x / 2.0;
 			let collider_right = collider_translation.x + collider_size.x / 2.0;
@@ -158,7 +282,8 @@ fn check_ground_collision(mut param_set: ParamSet<(ColliderQuery, GroundedQuery)
 			}
 
 			// Skip if not close to ground or moving upward
-			if entity_bottom > collider_top
+			// (a platform moving down may have dropped out from under its rider this frame)
+			if entity_bottom > collider_top - carry.y.min(0.0)
 				|| entity_bottom <= collider_top - crate::constants::GROUND_SNAP_DISTANCE
 				|| velocity.y > 0.0
 			{
@@ -168,7 +293,126 @@ fn check_ground_collision(mut param_set: ParamSet<(ColliderQuery, GroundedQuery)
 			grounded.0 = true;
 			velocity.y = 0.0;
 			entity_transform.translation.y = collider_top + entity_size.y / 2.0;
+			entity_transform.translation.x += carry.x;
+			if let Some(drop) = drop.as_mut() {
+				drop.on_one_way = *is_one_way;
+			}
+			if let Ok(mut occupied) = occupied_query.get_mut(*collider_entity) {
+				occupied.0 = true;
+			}
 			break;
 		}
 	}
 }
+
+// Block wall-contact entities at the sides of Ground colliders at least as tall as they are
+// (thin platforms stay passable from the side) and record which side is touching
+fn check_wall_collision(mut entity_query: WallContactQuery, wall_query: WallQuery) {
+	for (mut transform, sprite, mut velocity, mut wall_contact) in entity_query.iter_mut() {
+		let half_size = sprite.custom_size.unwrap_or(Vec2::ONE) / 2.0;
+		wall_contact.0 = 0.0;
+
+		for (wall_transform, wall_sprite) in wall_query.iter() {
+			let wall_half_size = wall_sprite.custom_size.unwrap_or(Vec2::ONE) / 2.0;
+			if wall_half_size.y < half_size.y {
+				continue;
+			}
+
+			let delta = transform.translation.truncate() - wall_transform.translation.truncate();
+
+			// Only the wall's side counts: our center must be within its vertical span
+			// (standing on top of it is handled by ground collision)
+			if delta.y.abs() >= wall_half_size.y {
+				continue;
+			}
+
+			let gap = delta.x.abs() - (half_size.x + wall_half_size.x);
+			if gap > crate::constants::WALL_CONTACT_DISTANCE {
+				continue;
+			}
+
+			// Wall is on the opposite side of the offset from its center
+			let side = -delta.x.signum();
+			if gap < 0.0 {
+				transform.translation.x += gap * side;
+			}
+			if velocity.x * side > 0.0 {
+				velocity.x = 0.0;
+			}
+			wall_contact.0 = side;
+			break;
+		}
+	}
+}
+
+// Projectiles are not Grounded, so platforms are handled here instead of check_ground_collision
+fn check_projectile_platform_collisions(
+	mut commands: Commands,
+	mut projectile_query: PlatformProjectileQuery,
+	platform_query: PlatformQuery,
+) {
+	use crate::behaviors::PlatformCollision;
+
+	for (entity, mut transform, sprite, mut velocity, mut collision) in projectile_query.iter_mut()
+	{
+		if matches!(collision.mode, PlatformCollision::PassThrough) {
+			continue;
+		}
+
+		let half_size = sprite.custom_size.unwrap_or(Vec2::ONE) / 2.0;
+
+		for (platform_transform, platform_sprite) in platform_query.iter() {
+			let platform_half_size = platform_sprite.custom_size.unwrap_or(Vec2::ONE) / 2.0;
+
+			let delta =
+				transform.translation.truncate() - platform_transform.translation.truncate();
+			let overlap = half_size + platform_half_size - delta.abs();
+
+			if overlap.x <= 0.0 || overlap.y <= 0.0 {
+				continue;
+			}
+
+			let impact_point = transform.translation.truncate();
+
+			let bounce = match collision.mode {
+				PlatformCollision::Bounce { max_bounces } => collision.bounces < max_bounces,
+				_ => false,
+			};
+
+			if bounce {
+				// Reflect on the axis with least overlap and push out of the platform
+				if overlap.x < overlap.y {
+					transform.translation.x += overlap.x * delta.x.signum();
+					velocity.x = -velocity.x;
+				} else {
+					transform.translation.y += overlap.y * delta.y.signum();
+					velocity.y = -velocity.y;
+				}
+				transform.rotation = Quat::from_rotation_z(velocity.y.atan2(velocity.x));
+				collision.bounces += 1;
+			} else {
+				// Only a projectile that stops here leaves an impact mark
+				commands.entity(entity).despawn();
+				spawn_impact_effect(&mut commands, impact_point, sprite.color);
+			}
+			break;
+		}
+	}
+}
+
+fn spawn_impact_effect(commands: &mut Commands, position: Vec2, color: Color) {
+	commands.spawn((
+		Sprite {
+			color: color.with_alpha(0.6),
+			custom_size: Some(crate::constants::PROJECTILE_IMPACT_SIZE),
+			..default()
+		},
+		Transform::from_translation(position.extend(1.0)),
+		crate::behaviors::DespawnOnTimer {
+			timer: Timer::from_seconds(
+				crate::constants::PROJECTILE_IMPACT_DURATION,
+				TimerMode::Once,
+			),
+		},
+	));
+}
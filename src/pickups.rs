@@ -0,0 +1,302 @@
+use bevy::prelude::*;
+use rand::{seq::SliceRandom, Rng};
+use serde::Deserialize;
+
+pub struct PickupsPlugin;
+
+impl Plugin for PickupsPlugin {
+	fn build(&self, app: &mut App) {
+		app.add_systems(Update, (move_pickups_to_player, collect_pickups));
+	}
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PickupType {
+	Health, // Heals `amount`
+	Energy, // Restores `amount` PlayerEnergy
+	Chest,  // Free weapon level, or a stat boost once every weapon is maxed
+	Magnet, // Pulls every XP orb on the map to the player
+}
+
+impl PickupType {
+	pub const ALL: [PickupType; 4] = [
+		PickupType::Health,
+		PickupType::Energy,
+		PickupType::Chest,
+		PickupType::Magnet,
+	];
+}
+
+/// Look and attraction of one pickup type, from game_config.ron.
+#[derive(Deserialize, Clone)]
+pub struct PickupSettings {
+	pub size: (f32, f32),
+	pub color: (f32, f32, f32),
+	pub attraction_range: f32, // Scales with pickup radius; 0 keeps the pickup in place
+	pub movement_speed: f32,
+	pub collection_range: f32,
+}
+
+/// One entry of an enemy's drop table, rolled independently on death.
+#[derive(Deserialize, Clone)]
+pub struct DropData {
+	pub pickup: PickupType,
+	pub chance: f64,
+	#[serde(default)]
+	pub amount: f32,
+}
+
+#[derive(Component)]
+pub struct Pickup {
+	pub pickup_type: PickupType,
+	pub amount: f32,
+	pub attraction_range: f32,
+	pub movement_speed: f32,
+	pub collection_range: f32,
+}
+
+// Matches the original magnet drop, with health, energy and chest pickups alongside it
+pub fn default_pickup_settings() -> std::collections::HashMap<PickupType, PickupSettings> {
+	use crate::constants::*;
+
+	std::collections::HashMap::from([
+		(
+			PickupType::Health,
+			PickupSettings {
+				size: (16.0, 16.0),
+				color: (0.9, 0.2, 0.3),
+				attraction_range: PLAYER_DEFAULT_PICKUP_RADIUS,
+				movement_speed: XP_ORB_MOVEMENT_SPEED,
+				collection_range: XP_ORB_COLLECTION_RANGE,
+			},
+		),
+		(
+			PickupType::Energy,
+			PickupSettings {
+				size: (14.0, 18.0),
+				color: (0.3, 0.8, 1.0),
+				attraction_range: PLAYER_DEFAULT_PICKUP_RADIUS,
+				movement_speed: XP_ORB_MOVEMENT_SPEED,
+				collection_range: XP_ORB_COLLECTION_RANGE,
+			},
+		),
+		(
+			PickupType::Chest,
+			PickupSettings {
+				size: (26.0, 20.0),
+				color: (0.8, 0.6, 0.2),
+				attraction_range: 0.0,
+				movement_speed: 0.0,
+				collection_range: XP_ORB_COLLECTION_RANGE,
+			},
+		),
+		(
+			PickupType::Magnet,
+			PickupSettings {
+				size: (18.0, 18.0),
+				color: (0.9, 0.2, 0.9),
+				attraction_range: 0.0,
+				movement_speed: 0.0,
+				collection_range: XP_ORB_COLLECTION_RANGE,
+			},
+		),
+	])
+}
+
+// Roll each drop table entry, scaled by luck, and spawn whatever comes up around the position
+pub fn spawn_drops(
+	commands: &mut Commands,
+	drops: &[DropData],
+	position: Vec3,
+	settings: &std::collections::HashMap<PickupType, PickupSettings>,
+	luck: f32,
+) {
+	let mut rng = rand::thread_rng();
+
+	for drop in drops {
+		if !rng.gen_bool((drop.chance * luck as f64).clamp(0.0, 1.0)) {
+			continue;
+		}
+		let Some(pickup_settings) = settings.get(&drop.pickup) else {
+			continue;
+		};
+
+		// Scatter drops so they don't stack on the XP orb
+		let offset = Vec3::new(
+			rng.gen_range(-crate::constants::PICKUP_SCATTER..=crate::constants::PICKUP_SCATTER),
+			rng.gen_range(-crate::constants::PICKUP_SCATTER..=crate::constants::PICKUP_SCATTER),
+			0.0,
+		);
+
+		let (r, g, b) = pickup_settings.color;
+		let (width, height) = pickup_settings.size;
+		commands.spawn((
+			Sprite {
+				color: Color::srgb(r, g, b),
+				custom_size: Some(Vec2::new(width, height)),
+				..default()
+			},
+			Transform::from_translation(position + offset),
+			Pickup {
+				pickup_type: drop.pickup,
+				amount: drop.amount,
+				attraction_range: pickup_settings.attraction_range,
+				movement_speed: pickup_settings.movement_speed,
+				collection_range: pickup_settings.collection_range,
+			},
+		));
+	}
+}
+
+fn move_pickups_to_player(
+	mut pickup_query: Query<(&mut Transform, &Pickup), Without<crate::player::Player>>,
+	player_query: Query<(&Transform, &crate::player::Player)>,
+	time: Res<Time<Virtual>>,
+) {
+	for (mut pickup_transform, pickup) in pickup_query.iter_mut() {
+		// Attraction range grows with the player's pickup radius like XP orbs do
+		let radius_scale = pickup.attraction_range / crate::constants::PLAYER_DEFAULT_PICKUP_RADIUS;
+		let Some(player_position) = crate::experience::nearest_player_in_radius(
+			pickup_transform.translation,
+			radius_scale,
+			player_query.iter(),
+		) else {
+			continue;
+		};
+
+		let direction = (player_position - pickup_transform.translation).normalize();
+		pickup_transform.translation += direction * pickup.movement_speed * time.delta_secs();
+	}
+}
+
+type PickupPlayerQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'static Transform,
+		&'static mut crate::player::Player,
+		&'static mut crate::behaviors::Damageable,
+		&'static mut crate::behaviors::PlayerEnergy,
+		&'static mut crate::player::PlayerStats,
+		&'static mut crate::weapons::WeaponInventory,
+		&'static mut crate::powerups::PowerupHistory,
+		&'static crate::powerups::PowerupActions,
+	),
+>;
+
+#[allow(clippy::too_many_arguments)]
+fn collect_pickups(
+	mut commands: Commands,
+	pickup_query: Query<(Entity, &Transform, &Pickup)>,
+	mut player_query: PickupPlayerQuery,
+	orb_query: Query<Entity, With<crate::experience::ExperienceOrb>>,
+	mut weapon_level_query: Query<&mut crate::behaviors::WeaponLevel>,
+	weapon_resources: crate::powerups::WeaponResources,
+	game_config: Option<Res<crate::GameConfig>>,
+	config_assets: Res<Assets<crate::GameConfigData>>,
+) {
+	for (pickup_entity, pickup_transform, pickup) in pickup_query.iter() {
+		// Nearest player within the pickup's collection range takes it
+		let collector = player_query
+			.iter_mut()
+			.filter(|(_, player_transform, ..)| {
+				player_transform
+					.translation
+					.distance(pickup_transform.translation)
+					< pickup.collection_range
+			})
+			.min_by(|(_, a, ..), (_, b, ..)| {
+				let dist_a = a.translation.distance(pickup_transform.translation);
+				let dist_b = b.translation.distance(pickup_transform.translation);
+				dist_a.partial_cmp(&dist_b).unwrap()
+			});
+		let Some((
+			player_entity,
+			_,
+			mut player,
+			mut damageable,
+			mut energy,
+			mut stats,
+			mut weapon_inventory,
+			mut powerup_history,
+			powerup_actions,
+		)) = collector
+		else {
+			continue;
+		};
+
+		match pickup.pickup_type {
+			PickupType::Health => {
+				damageable.health = (damageable.health + pickup.amount).min(damageable.max_health);
+			}
+			PickupType::Energy => {
+				energy.current = (energy.current + pickup.amount).min(energy.max);
+			}
+			PickupType::Chest => {
+				let Some(config_data) = game_config
+					.as_ref()
+					.and_then(|config| config_assets.get(&config.config_handle))
+				else {
+					continue;
+				};
+
+				// Level up a random owned weapon the pool still offers; stat boosts once
+				// every weapon is maxed, banished or excluded
+				let (upgradable, stat_boosts): (Vec<_>, Vec<_>) =
+					crate::powerups::application::available_powerups(
+						config_data,
+						&weapon_resources,
+						&weapon_inventory,
+						&powerup_history,
+						powerup_actions,
+					)
+					.into_iter()
+					.map(|entry| entry.powerup.clone())
+					.filter(|powerup| match powerup {
+						crate::PowerupDefinition::Weapon(id) => {
+							weapon_inventory.weapons.contains_key(id)
+						}
+						crate::PowerupDefinition::StatBoost(_) => true,
+						_ => false,
+					})
+					.partition(|powerup| matches!(powerup, crate::PowerupDefinition::Weapon(_)));
+				let options = if upgradable.is_empty() {
+					stat_boosts
+				} else {
+					upgradable
+				};
+
+				if let Some(reward) = options.choose(&mut rand::thread_rng()) {
+					crate::powerups::application::apply_powerup(
+						reward,
+						&mut commands,
+						(
+							player_entity,
+							&mut player,
+							&mut damageable,
+							&mut energy,
+							&mut stats,
+						),
+						&weapon_resources,
+						&mut weapon_inventory,
+						&mut weapon_level_query,
+						&mut powerup_history,
+					);
+				}
+			}
+			PickupType::Magnet => {
+				// Orbs collected or merged this frame are already queued for despawn
+				for orb_entity in orb_query.iter() {
+					commands
+						.entity(orb_entity)
+						.try_insert(crate::experience::Magnetized {
+							player: player_entity,
+						});
+				}
+			}
+		}
+
+		commands.entity(pickup_entity).despawn();
+	}
+}
@@ -0,0 +1,181 @@
+use bevy::prelude::*;
+
+pub struct PlatformsPlugin;
+
+impl Plugin for PlatformsPlugin {
+	fn build(&self, app: &mut App) {
+		app.add_systems(
+			Update,
+			update_moving_platforms.before(crate::physics::PhysicsSet),
+		)
+		.add_systems(
+			Update,
+			(update_crumbling_platforms, update_timed_platforms).after(crate::physics::PhysicsSet),
+		);
+	}
+}
+
+/// Platform that travels between waypoints in a loop. Movement goes through its Velocity,
+/// so ground collision can carry riders along.
+#[derive(Component)]
+pub struct MovingPlatform {
+	pub waypoints: Vec<Vec2>,
+	pub speed: f32,
+	pub target: usize, // Index of the waypoint being travelled to
+}
+
+pub enum CrumbleState {
+	Solid,
+	Crumbling(f32), // Time since something first stood on it
+	Gone(f32),      // Time since it disappeared
+}
+
+/// Platform that breaks shortly after being stood on, then respawns.
+#[derive(Component)]
+pub struct CrumblingPlatform {
+	pub crumble_time: f32,
+	pub respawn_time: f32,
+	pub state: CrumbleState,
+}
+
+/// Platform that appears and disappears on a fixed cycle.
+#[derive(Component)]
+pub struct TimedPlatform {
+	pub visible_time: f32,
+	pub hidden_time: f32,
+	pub elapsed: f32,
+	pub visible: bool,
+}
+
+fn update_moving_platforms(
+	mut query: Query<(
+		&Transform,
+		&mut crate::physics::Velocity,
+		&mut MovingPlatform,
+	)>,
+	time: Res<Time<Virtual>>,
+) {
+	let delta = time.delta_secs();
+	if delta <= 0.0 {
+		return;
+	}
+
+	for (transform, mut velocity, mut platform) in query.iter_mut() {
+		let Some(&target) = platform.waypoints.get(platform.target) else {
+			continue;
+		};
+
+		let to_target = target - transform.translation.truncate();
+		let step = platform.speed * delta;
+
+		// Land exactly on the waypoint, then head for the next one
+		let new_velocity = if to_target.length() <= step {
+			platform.target = (platform.target + 1) % platform.waypoints.len();
+			to_target / delta
+		} else {
+			to_target.normalize() * platform.speed
+		};
+
+		velocity.x = new_velocity.x;
+		velocity.y = new_velocity.y;
+	}
+}
+
+fn update_crumbling_platforms(
+	mut commands: Commands,
+	mut query: Query<(
+		Entity,
+		&mut CrumblingPlatform,
+		&crate::physics::Occupied,
+		&mut Sprite,
+		&mut Visibility,
+	)>,
+	time: Res<Time<Virtual>>,
+) {
+	for (entity, mut platform, occupied, mut sprite, mut visibility) in query.iter_mut() {
+		let crumble_time = platform.crumble_time;
+		let respawn_time = platform.respawn_time;
+
+		match &mut platform.state {
+			CrumbleState::Solid => {
+				if occupied.0 {
+					platform.state = CrumbleState::Crumbling(0.0);
+				}
+			}
+			CrumbleState::Crumbling(elapsed) => {
+				*elapsed += time.delta_secs();
+
+				// Fade out as it's about to break
+				let remaining = 1.0 - (*elapsed / crumble_time).min(1.0);
+				sprite.color.set_alpha(0.3 + 0.7 * remaining);
+
+				if *elapsed >= crumble_time {
+					set_platform_solid(&mut commands, entity, &mut visibility, false);
+					platform.state = CrumbleState::Gone(0.0);
+				}
+			}
+			CrumbleState::Gone(elapsed) => {
+				*elapsed += time.delta_secs();
+
+				if *elapsed >= respawn_time {
+					sprite.color.set_alpha(1.0);
+					set_platform_solid(&mut commands, entity, &mut visibility, true);
+					platform.state = CrumbleState::Solid;
+				}
+			}
+		}
+	}
+}
+
+fn update_timed_platforms(
+	mut commands: Commands,
+	mut query: Query<(Entity, &mut TimedPlatform, &mut Sprite, &mut Visibility)>,
+	time: Res<Time<Virtual>>,
+) {
+	for (entity, mut platform, mut sprite, mut visibility) in query.iter_mut() {
+		platform.elapsed += time.delta_secs();
+
+		let phase_time = if platform.visible {
+			platform.visible_time
+		} else {
+			platform.hidden_time
+		};
+
+		if platform.elapsed >= phase_time {
+			platform.elapsed = 0.0;
+			platform.visible = !platform.visible;
+			set_platform_solid(&mut commands, entity, &mut visibility, platform.visible);
+		}
+
+		// Blink shortly before disappearing
+		let warning = platform.visible
+			&& platform.visible_time - platform.elapsed
+				< crate::constants::TIMED_PLATFORM_WARNING_TIME;
+		let alpha = if warning && (platform.elapsed * 20.0).sin() > 0.0 {
+			0.4
+		} else {
+			1.0
+		};
+		sprite.color.set_alpha(alpha);
+	}
+}
+
+// Removing Ground and Collider together keeps a hidden platform out of every collision query
+fn set_platform_solid(
+	commands: &mut Commands,
+	entity: Entity,
+	visibility: &mut Visibility,
+	solid: bool,
+) {
+	if solid {
+		commands
+			.entity(entity)
+			.insert((crate::physics::Ground, crate::physics::Collider));
+		*visibility = Visibility::Inherited;
+	} else {
+		commands
+			.entity(entity)
+			.remove::<(crate::physics::Ground, crate::physics::Collider)>();
+		*visibility = Visibility::Hidden;
+	}
+}
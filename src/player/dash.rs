@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+
+type DashQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'static mut crate::physics::Velocity,
+		&'static super::Player,
+		&'static crate::input::PlayerController,
+		&'static mut super::DashState,
+		&'static mut crate::behaviors::PlayerEnergy,
+		Has<crate::behaviors::Dashing>,
+	),
+	Without<crate::behaviors::EnergyCharging>,
+>;
+
+pub fn player_dash(
+	mut commands: Commands,
+	input: crate::input::ActionInput,
+	mut query: DashQuery,
+	powerup_state: Res<crate::powerups::PowerupState>,
+	time: Res<Time>, // Use real time for input, not virtual (paused) time
+) {
+	// Don't process dash input while menu is showing
+	if powerup_state.showing {
+		return;
+	}
+
+	for (entity, mut velocity, player, controller, mut dash, mut energy, is_dashing) in
+		query.iter_mut()
+	{
+		// Recharge spent charges one at a time
+		let cooldown = std::time::Duration::from_secs_f32(player.dash_cooldown);
+		if dash.recharge.duration() != cooldown {
+			dash.recharge.set_duration(cooldown);
+		}
+		if dash.charges < player.dash_charges {
+			dash.recharge.tick(time.delta());
+			if dash.recharge.just_finished() {
+				dash.charges += 1;
+			}
+		}
+
+		if is_dashing {
+			dash.timer -= time.delta_secs();
+			if dash.timer > 0.0 {
+				velocity.x = dash.direction.x * dash.speed;
+				velocity.y = dash.direction.y * dash.speed;
+			} else {
+				// Carry running speed out of the dash, drop the vertical burst
+				velocity.x = dash.direction.x * player.speed;
+				velocity.y = 0.0;
+				commands
+					.entity(entity)
+					.remove::<crate::behaviors::Dashing>();
+			}
+			continue;
+		}
+
+		if velocity.x.abs() > 0.01 {
+			dash.facing = velocity.x.signum();
+		}
+
+		let dash_pressed = input.just_pressed(controller, crate::input::InputAction::Dash);
+		if !dash_pressed || dash.charges == 0 || energy.current < dash.energy_cost {
+			continue;
+		}
+
+		// Start recharging from zero when the first charge is spent
+		if dash.charges == player.dash_charges {
+			dash.recharge.reset();
+		}
+		dash.charges -= 1;
+		energy.current -= dash.energy_cost;
+
+		// Dash along the held direction, or straight ahead without input
+		let direction = Vec2::new(input.move_x(controller), input.move_y(controller));
+		dash.direction = if direction == Vec2::ZERO {
+			Vec2::new(dash.facing, 0.0)
+		} else {
+			direction.normalize()
+		};
+		dash.timer = dash.duration;
+		velocity.x = dash.direction.x * dash.speed;
+		velocity.y = dash.direction.y * dash.speed;
+
+		commands.entity(entity).insert((
+			crate::behaviors::Dashing,
+			crate::behaviors::Invulnerable {
+				timer: Timer::from_seconds(dash.iframe_time, TimerMode::Once),
+			},
+		));
+	}
+}
@@ -26,6 +26,7 @@ type ChargingInputPlayerQuery<'w, 's> = Query<
 		&'static mut crate::physics::Velocity,
 		Has<crate::behaviors::EnergyCharging>,
 		&'static crate::behaviors::PlayerEnergy,
+		&'static crate::input::PlayerController,
 	),
 	With<super::Player>,
 >;
@@ -44,7 +45,9 @@ type RepulsionEnemyQuery<'w, 's> = Query<
 >;
 
 #[derive(Component)]
-pub struct RepulsionFieldIndicator;
+pub struct RepulsionFieldIndicator {
+	pub player: Entity, // Charging player the field is drawn around
+}
 
 pub fn regenerate_energy(
 	mut player_query: Query<&mut crate::behaviors::PlayerEnergy, With<super::Player>>,
@@ -57,10 +60,9 @@ pub fn regenerate_energy(
 
 pub fn handle_energy_charging_input(
 	mut commands: Commands,
-	keyboard: Res<ButtonInput<KeyCode>>,
-	gamepads: Query<&Gamepad>,
+	input: crate::input::ActionInput,
 	mut player_query: ChargingInputPlayerQuery,
-	indicator_query: Query<Entity, With<RepulsionFieldIndicator>>,
+	indicator_query: Query<(Entity, &RepulsionFieldIndicator)>,
 	powerup_state: Res<crate::powerups::PowerupState>,
 	(mut meshes, mut materials): (ResMut<Assets<Mesh>>, ResMut<Assets<ColorMaterial>>),
 ) {
@@ -69,24 +71,18 @@ pub fn handle_energy_charging_input(
 		return;
 	}
 
-	for (player_entity, player_transform, mut velocity, is_charging, player_energy) in player_query.iter_mut() {
-		let mut charging_input = false;
-
-		// Check keyboard (F key)
-		if keyboard.pressed(KeyCode::KeyF) {
-			charging_input = true;
-		}
-
-		// Check gamepad (Y button / North)
-		for gamepad in gamepads.iter() {
-			if gamepad.pressed(GamepadButton::North) {
-				charging_input = true;
-			}
-		}
+	for (player_entity, player_transform, mut velocity, is_charging, player_energy, controller) in
+		player_query.iter_mut()
+	{
+		let charging_input = input.pressed(controller, crate::input::InputAction::Charge);
 
 		if charging_input && !is_charging {
-			// Start charging
-			commands.entity(player_entity).insert(crate::behaviors::EnergyCharging);
+			// Start charging; this cancels a dash in progress, since dashes only update
+			// while not charging
+			commands
+				.entity(player_entity)
+				.insert(crate::behaviors::EnergyCharging)
+				.remove::<(crate::behaviors::Dashing, crate::behaviors::Invulnerable)>();
 			velocity.x = 0.0;
 			velocity.y = 0.0;
 
@@ -124,7 +120,9 @@ pub fn handle_energy_charging_input(
 						Mesh2d(mesh_handle),
 						MeshMaterial2d(material_handle),
 						Transform::from_translation(player_transform.translation.with_z(-1.0)),
-						RepulsionFieldIndicator,
+						RepulsionFieldIndicator {
+							player: player_entity,
+						},
 					));
 				}
 			}
@@ -132,9 +130,11 @@ pub fn handle_energy_charging_input(
 			// Stop charging
 			commands.entity(player_entity).remove::<crate::behaviors::EnergyCharging>();
 
-			// Despawn all repulsion field indicators
-			for indicator_entity in indicator_query.iter() {
-				commands.entity(indicator_entity).despawn();
+			// Despawn this player's repulsion field indicators
+			for (indicator_entity, indicator) in indicator_query.iter() {
+				if indicator.player == player_entity {
+					commands.entity(indicator_entity).despawn();
+				}
 			}
 		}
 	}
@@ -161,11 +161,11 @@ pub fn apply_repulsion_field(
 	const MAX_FORCE: f32 = crate::constants::MAX_REPULSION_FORCE;
 	const BASE_SPEED: f32 = crate::constants::REPULSION_BASE_SPEED;
 
-	// Only apply if player is charging
-	if let Ok((player_transform, player_energy)) = player_query.single() {
+	// Only apply around players who are charging
+	for (player_transform, player_energy) in player_query.iter() {
 		// Skip if repulsion force is zero (no powerup acquired yet)
 		if player_energy.repulsion_force <= 0.0 {
-			return;
+			continue;
 		}
 
 		// Calculate effective range based on current repulsion force
@@ -217,12 +217,12 @@ pub fn apply_repulsion_field(
 
 pub fn cleanup_repulsion_markers(
 	mut commands: Commands,
-	player_query: Query<(), (With<super::Player>, Without<crate::behaviors::EnergyCharging>)>,
+	player_query: Query<(), (With<super::Player>, With<crate::behaviors::EnergyCharging>)>,
 	marked_enemies: Query<Entity, With<crate::behaviors::InRepulsionField>>,
 ) {
-	// Only cleanup if player is NOT charging
-	if player_query.is_empty() {
-		return; // Player doesn't exist or is charging
+	// Only cleanup once no player is charging
+	if !player_query.is_empty() {
+		return;
 	}
 
 	// Remove InRepulsionField marker from all marked enemies
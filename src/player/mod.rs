@@ -1,16 +1,18 @@
 use bevy::prelude::*;
 
+mod dash;
 mod energy;
 mod movement;
 mod spawning;
+mod stats;
 mod ui;
 
 // Re-export public items if needed in the future
 // pub use energy::RepulsionFieldIndicator;
 // pub use spawning::NeedsInitialWeapons;
 // pub use ui::{
-// 	EnergyBarBackground, EnergyBarForeground, EnergyText, PlayerStatsText, XPBarBackground,
-// 	XPBarForeground, XPText,
+// 	EnergyBarBackground, EnergyBarForeground, EnergyText, PlayerHud, PlayerStatsText,
+// 	XPBarBackground, XPBarForeground, XPText,
 // };
 
 pub struct PlayerPlugin;
@@ -23,6 +25,9 @@ impl Plugin for PlayerPlugin {
 				// Process input before physics for minimal latency
 				movement::player_movement,
 				movement::player_jump,
+				dash::player_dash
+					.after(movement::player_movement)
+					.after(movement::player_jump),
 				energy::handle_energy_charging_input,
 			)
 				.before(crate::physics::PhysicsSet),
@@ -31,12 +36,16 @@ impl Plugin for PlayerPlugin {
 			Update,
 			(
 				spawning::spawn_player,
+				spawning::join_players,
 				spawning::spawn_initial_weapon,
+				ui::sync_player_hud,
 				ui::update_player_stats_display,
 				ui::update_xp_bar,
 				energy::regenerate_energy,
 				energy::charge_energy,
 				ui::update_energy_bar,
+				stats::regenerate_health,
+				stats::apply_lifesteal,
 			),
 		)
 		.add_systems(
@@ -52,6 +61,12 @@ impl Plugin for PlayerPlugin {
 pub struct Player {
 	pub speed: f32,
 	pub jump_force: f32,
+	pub extra_jumps: u32,
+	pub dash_charges: u32,
+	pub dash_cooldown: f32,
+	pub pickup_radius: f32,
+	pub xp_multiplier: f32,     // Scales the XP of every collected orb
+	pub powerup_options: usize, // Cards offered on each level-up menu
 	pub level: u32,
 }
 
@@ -60,7 +75,125 @@ impl Default for Player {
 		Self {
 			speed: crate::constants::PLAYER_DEFAULT_SPEED,
 			jump_force: crate::constants::PLAYER_DEFAULT_JUMP_FORCE,
+			extra_jumps: crate::constants::PLAYER_DEFAULT_EXTRA_JUMPS,
+			dash_charges: crate::constants::PLAYER_DEFAULT_DASH_CHARGES,
+			dash_cooldown: crate::constants::PLAYER_DASH_COOLDOWN,
+			pickup_radius: crate::constants::PLAYER_DEFAULT_PICKUP_RADIUS,
+			xp_multiplier: crate::constants::PLAYER_DEFAULT_XP_MULTIPLIER,
+			powerup_options: crate::constants::POWERUP_OPTIONS_COUNT,
 			level: 1,
 		}
 	}
 }
+
+/// Global modifiers raised by stat boosts, read by every weapon when it fires or hits.
+#[derive(Component, Default, Clone, Copy)]
+pub struct PlayerStats {
+	pub might: f32,        // Bonus damage, 0.1 is +10%
+	pub area: f32,         // Bonus size of projectiles, hitboxes and explosions
+	pub cooldown: f32,     // Cooldown reduction, 0.1 is -10%
+	pub amount: u32,       // Extra projectiles per volley and extra summons
+	pub armor: f32,        // Flat damage removed from each hit taken
+	pub luck: f32,         // Bonus drop chance and weight of non-common powerups
+	pub lifesteal: f32,    // Fraction of damage dealt returned as health
+	pub health_regen: f32, // Health per second
+}
+
+impl PlayerStats {
+	// Stats of a weapon's owning player; enemies and unowned weapons get neutral stats
+	pub fn for_owner(stats_query: &Query<&PlayerStats>, owner: Option<Entity>) -> Self {
+		owner
+			.and_then(|owner| stats_query.get(owner).ok())
+			.copied()
+			.unwrap_or_default()
+	}
+
+	pub fn damage_multiplier(&self) -> f32 {
+		(1.0 + self.might).max(0.0)
+	}
+
+	pub fn area_multiplier(&self) -> f32 {
+		(1.0 + self.area).max(crate::constants::PLAYER_MIN_AREA_MULTIPLIER)
+	}
+
+	// How much faster weapon cooldowns tick
+	pub fn cooldown_speed(&self) -> f32 {
+		let reduction = self
+			.cooldown
+			.min(crate::constants::PLAYER_MAX_COOLDOWN_REDUCTION);
+		1.0 / (1.0 - reduction)
+	}
+
+	pub fn luck_multiplier(&self) -> f32 {
+		(1.0 + self.luck).max(0.0)
+	}
+
+	// Armor never blocks a hit completely
+	pub fn damage_taken(&self, damage: f32) -> f32 {
+		(damage - self.armor).max(damage * crate::constants::PLAYER_MIN_ARMOR_DAMAGE)
+	}
+}
+
+/// Per-player jump timers and tuning (coyote time, input buffer, variable height, wall jumps).
+#[derive(Component)]
+pub struct JumpState {
+	pub coyote_time: f32,
+	pub buffer_time: f32,
+	pub cut_multiplier: f32,
+	pub wall_slide_speed: f32,
+	pub wall_jump_push: f32,
+	pub wall_jump_lock_time: f32,
+	pub coyote_timer: f32, // Time left to jump after walking off a ledge
+	pub buffer_timer: f32, // Time left on a buffered jump press
+	pub air_jumps_used: u32,
+	pub rising: bool,       // Jump still held and ascending, can be cut short
+	pub steering_lock: f32, // Time left before movement input applies after a wall jump
+}
+
+impl JumpState {
+	pub fn from_config(config: &crate::PlayerMovementConfig) -> Self {
+		Self {
+			coyote_time: config.coyote_time,
+			buffer_time: config.jump_buffer_time,
+			cut_multiplier: config.jump_cut_multiplier,
+			wall_slide_speed: config.wall_slide_speed,
+			wall_jump_push: config.wall_jump_push,
+			wall_jump_lock_time: config.wall_jump_lock_time,
+			coyote_timer: 0.0,
+			buffer_timer: 0.0,
+			air_jumps_used: 0,
+			rising: false,
+			steering_lock: 0.0,
+		}
+	}
+}
+
+/// Per-player dash tuning, charges and timers. Charge count and recharge time live on Player.
+#[derive(Component)]
+pub struct DashState {
+	pub speed: f32,
+	pub duration: f32,
+	pub energy_cost: f32,
+	pub iframe_time: f32,
+	pub charges: u32,    // Charges ready to use
+	pub recharge: Timer, // Restores one charge each time it finishes
+	pub timer: f32,      // Time left in the current dash
+	pub direction: Vec2,
+	pub facing: f32, // Last horizontal direction, used when dashing without input
+}
+
+impl DashState {
+	pub fn from_config(config: &crate::PlayerMovementConfig) -> Self {
+		Self {
+			speed: config.dash_speed,
+			duration: config.dash_duration,
+			energy_cost: config.dash_energy_cost,
+			iframe_time: config.dash_iframe_time,
+			charges: config.dash_charges,
+			recharge: Timer::from_seconds(config.dash_cooldown, TimerMode::Repeating),
+			timer: 0.0,
+			direction: Vec2::ZERO,
+			facing: 1.0,
+		}
+	}
+}
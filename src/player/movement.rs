@@ -1,39 +1,32 @@
 use bevy::prelude::*;
 
+type MovementQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		&'static mut crate::physics::Velocity,
+		&'static super::Player,
+		&'static crate::input::PlayerController,
+		Option<&'static super::JumpState>,
+	),
+	(
+		Without<crate::behaviors::EnergyCharging>,
+		Without<crate::behaviors::Dashing>,
+	),
+>;
+
 pub fn player_movement(
-	keyboard: Res<ButtonInput<KeyCode>>,
-	gamepads: Query<&Gamepad>,
-	mut query: Query<(&mut crate::physics::Velocity, &super::Player), Without<crate::behaviors::EnergyCharging>>,
+	input: crate::input::ActionInput,
+	mut query: MovementQuery,
 	time: Res<Time>, // Use real time for input, not virtual (paused) time
 ) {
-	for (mut velocity, player) in query.iter_mut() {
-		let mut direction = 0.0;
-
-		// Keyboard input
-		if keyboard.pressed(KeyCode::KeyA) || keyboard.pressed(KeyCode::ArrowLeft) {
-			direction -= 1.0;
-		}
-		if keyboard.pressed(KeyCode::KeyD) || keyboard.pressed(KeyCode::ArrowRight) {
-			direction += 1.0;
+	for (mut velocity, player, controller, jump) in query.iter_mut() {
+		// Keep the wall jump's push away from the wall for a moment
+		if jump.is_some_and(|jump| jump.steering_lock > 0.0) {
+			continue;
 		}
 
-		// Gamepad input
-		for gamepad in gamepads.iter() {
-			// Left stick X axis
-			if let Some(axis_value) = gamepad.get(GamepadAxis::LeftStickX) {
-				if axis_value.abs() > crate::constants::GAMEPAD_DEADZONE {
-					direction = axis_value;
-				}
-			}
-
-			// D-pad as alternative
-			if gamepad.pressed(GamepadButton::DPadLeft) {
-				direction = -1.0;
-			}
-			if gamepad.pressed(GamepadButton::DPadRight) {
-				direction = 1.0;
-			}
-		}
+		let direction = input.move_x(controller);
 
 		// Acceleration-based movement
 		let target_speed = direction * player.speed;
@@ -59,41 +52,103 @@ pub fn player_movement(
 	}
 }
 
+type JumpQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		&'static mut crate::physics::Velocity,
+		&'static super::Player,
+		&'static crate::input::PlayerController,
+		&'static mut super::JumpState,
+		&'static crate::physics::Grounded,
+		&'static crate::physics::WallContact,
+		&'static mut crate::physics::DropThrough,
+	),
+	Without<crate::behaviors::EnergyCharging>,
+>;
+
 pub fn player_jump(
-	keyboard: Res<ButtonInput<KeyCode>>,
-	gamepads: Query<&Gamepad>,
-	mut query: Query<
-		(
-			&mut crate::physics::Velocity,
-			&super::Player,
-			&crate::physics::Grounded,
-		),
-		Without<crate::behaviors::EnergyCharging>,
-	>,
+	input: crate::input::ActionInput,
+	mut query: JumpQuery,
 	powerup_state: Res<crate::powerups::PowerupState>,
+	time: Res<Time>, // Use real time for input, not virtual (paused) time
 ) {
 	// Don't process jump input while menu is showing
 	if powerup_state.showing {
 		return;
 	}
 
-	for (mut velocity, player, grounded) in query.iter_mut() {
-		let mut should_jump = false;
+	use crate::input::InputAction;
 
-		// Keyboard input
-		if keyboard.just_pressed(KeyCode::Space) || keyboard.just_pressed(KeyCode::KeyW) {
-			should_jump = true;
+	let delta = time.delta_secs();
+
+	for (mut velocity, player, controller, mut jump, grounded, wall_contact, mut drop) in
+		query.iter_mut()
+	{
+		let jump_pressed = input.just_pressed(controller, InputAction::Jump);
+		let jump_held = input.pressed(controller, InputAction::Jump);
+		let down_held = input.move_y(controller) < -0.5;
+
+		let on_wall = !grounded.0 && wall_contact.0 != 0.0;
+		jump.steering_lock = (jump.steering_lock - delta).max(0.0);
+
+		// Down + jump on a one-way platform drops through it instead of jumping
+		if jump_pressed && down_held && grounded.0 && drop.on_one_way {
+			drop.timer = crate::constants::PLATFORM_DROP_THROUGH_TIME;
+			jump.coyote_timer = 0.0;
+			jump.buffer_timer = 0.0;
+			continue;
+		}
+
+		// Coyote time: ground jumps stay available briefly after leaving a ledge
+		if grounded.0 {
+			jump.coyote_timer = jump.coyote_time;
+			jump.air_jumps_used = 0;
+		} else {
+			jump.coyote_timer = (jump.coyote_timer - delta).max(0.0);
+		}
+
+		// Jump buffer: remember a press shortly before landing
+		if jump_pressed {
+			jump.buffer_timer = jump.buffer_time;
+		} else {
+			jump.buffer_timer = (jump.buffer_timer - delta).max(0.0);
 		}
 
-		// Gamepad input (South button - typically A on Xbox, Cross on PlayStation)
-		for gamepad in gamepads.iter() {
-			if gamepad.just_pressed(GamepadButton::South) {
-				should_jump = true;
+		if jump.buffer_timer > 0.0 {
+			let can_ground_jump = grounded.0 || jump.coyote_timer > 0.0;
+			// Air jumps need a fresh press so a buffered press doesn't burn one just before landing
+			let can_air_jump = jump_pressed && jump.air_jumps_used < player.extra_jumps;
+
+			if can_ground_jump || on_wall || can_air_jump {
+				if !can_ground_jump && on_wall {
+					// Wall jump kicks away from the wall
+					velocity.x = -wall_contact.0 * jump.wall_jump_push;
+					jump.steering_lock = jump.wall_jump_lock_time;
+				} else if !can_ground_jump {
+					jump.air_jumps_used += 1;
+				}
+				velocity.y = player.jump_force;
+				jump.coyote_timer = 0.0;
+				jump.buffer_timer = 0.0;
+				jump.rising = true;
+				continue;
 			}
 		}
 
-		if should_jump && grounded.0 {
-			velocity.y = player.jump_force;
+		// Wall slide: cap fall speed while touching a wall in the air
+		if on_wall && velocity.y < -jump.wall_slide_speed {
+			velocity.y = -jump.wall_slide_speed;
+		}
+
+		// Variable jump height: releasing early cuts the remaining upward velocity
+		if jump.rising {
+			if velocity.y <= 0.0 {
+				jump.rising = false;
+			} else if !jump_held {
+				velocity.y *= jump.cut_multiplier;
+				jump.rising = false;
+			}
 		}
 	}
 }
@@ -5,8 +5,8 @@ pub struct NeedsInitialWeapons {
 	pub weapons: Vec<crate::InitialWeapon>,
 }
 
-fn spawn_platform(commands: &mut Commands, position: Vec3, size: Vec2) {
-	commands.spawn((
+fn spawn_platform(commands: &mut Commands, position: Vec3, size: Vec2, one_way: bool) -> Entity {
+	let mut platform = commands.spawn((
 		Sprite {
 			color: crate::constants::PLATFORM_COLOR,
 			custom_size: Some(size),
@@ -16,35 +16,93 @@ fn spawn_platform(commands: &mut Commands, position: Vec3, size: Vec2) {
 		crate::physics::Ground,
 		crate::physics::Collider,
 	));
+	if one_way {
+		platform.insert(crate::physics::OneWay);
+	}
+	platform.id()
 }
 
 pub fn spawn_platforms(commands: &mut Commands) {
 	const GROUND_SIZE: Vec2 = Vec2::new(2000.0, 40.0);
 	const STAIR_SIZE: Vec2 = Vec2::new(150.0, 20.0);
 	const TOP_PLATFORM_SIZE: Vec2 = Vec2::new(200.0, 20.0);
+	const WALL_SIZE: Vec2 = Vec2::new(20.0, 400.0);
+	const MOVING_PLATFORM_SIZE: Vec2 = Vec2::new(120.0, 20.0);
 
-	const PLATFORMS: [(Vec3, Vec2); 8] = [
-		(Vec3::new(0.0, -300.0, 0.0), GROUND_SIZE),
-		(Vec3::new(-200.0, -240.0, 0.0), STAIR_SIZE),
-		(Vec3::new(-400.0, -180.0, 0.0), STAIR_SIZE),
-		(Vec3::new(-200.0, -120.0, 0.0), STAIR_SIZE),
-		(Vec3::new(200.0, -240.0, 0.0), STAIR_SIZE),
-		(Vec3::new(400.0, -180.0, 0.0), STAIR_SIZE),
-		(Vec3::new(200.0, -120.0, 0.0), STAIR_SIZE),
-		(Vec3::new(0.0, -60.0, 0.0), TOP_PLATFORM_SIZE),
+	// (position, size, one_way): one-way platforms can be dropped through with down + jump
+	const PLATFORMS: [(Vec3, Vec2, bool); 10] = [
+		(Vec3::new(0.0, -300.0, 0.0), GROUND_SIZE, false),
+		(Vec3::new(-200.0, -240.0, 0.0), STAIR_SIZE, true),
+		(Vec3::new(-400.0, -180.0, 0.0), STAIR_SIZE, true),
+		(Vec3::new(-200.0, -120.0, 0.0), STAIR_SIZE, true),
+		(Vec3::new(200.0, -240.0, 0.0), STAIR_SIZE, true),
+		(Vec3::new(400.0, -180.0, 0.0), STAIR_SIZE, true),
+		(Vec3::new(200.0, -120.0, 0.0), STAIR_SIZE, true),
+		(Vec3::new(0.0, -60.0, 0.0), TOP_PLATFORM_SIZE, true),
+		// Arena walls at both ends of the ground, climbable with wall jumps
+		(Vec3::new(-990.0, -80.0, 0.0), WALL_SIZE, false),
+		(Vec3::new(990.0, -80.0, 0.0), WALL_SIZE, false),
 	];
 
-	PLATFORMS
-		.iter()
-		.for_each(|(pos, size)| spawn_platform(commands, *pos, *size));
+	PLATFORMS.iter().for_each(|(pos, size, one_way)| {
+		spawn_platform(commands, *pos, *size, *one_way);
+	});
+
+	// Lift on the left carries riders up toward the wall
+	let lift = spawn_platform(
+		commands,
+		Vec3::new(-750.0, -240.0, 0.0),
+		MOVING_PLATFORM_SIZE,
+		false,
+	);
+	commands.entity(lift).insert((
+		crate::platforms::MovingPlatform {
+			waypoints: vec![Vec2::new(-750.0, -240.0), Vec2::new(-750.0, 0.0)],
+			speed: 80.0,
+			target: 1,
+		},
+		crate::physics::Velocity { x: 0.0, y: 0.0 },
+	));
+
+	// Crumbling ledge on the right
+	let ledge = spawn_platform(
+		commands,
+		Vec3::new(700.0, -160.0, 0.0),
+		MOVING_PLATFORM_SIZE,
+		true,
+	);
+	commands.entity(ledge).insert((
+		crate::platforms::CrumblingPlatform {
+			crumble_time: 0.6,
+			respawn_time: 3.0,
+			state: crate::platforms::CrumbleState::Solid,
+		},
+		crate::physics::Occupied::default(),
+	));
+
+	// Timed platform above the top platform
+	let timed = spawn_platform(
+		commands,
+		Vec3::new(0.0, 20.0, 0.0),
+		MOVING_PLATFORM_SIZE,
+		true,
+	);
+	commands
+		.entity(timed)
+		.insert(crate::platforms::TimedPlatform {
+			visible_time: 3.0,
+			hidden_time: 2.0,
+			elapsed: 0.0,
+			visible: true,
+		});
 }
 
 pub fn spawn_player(
 	mut commands: Commands,
 	game_config: Option<Res<crate::GameConfig>>,
 	config_assets: Res<Assets<crate::GameConfigData>>,
+	characters: crate::character::CharacterResources,
 	player_query: Query<(), With<super::Player>>,
-	ui_query: Query<(), With<super::ui::PlayerStatsText>>,
 	platform_query: Query<(), With<crate::physics::Ground>>,
 ) {
 	// Only spawn once
@@ -60,56 +118,215 @@ pub fn spawn_player(
 		return;
 	};
 
-	commands.spawn((
-		Sprite {
-			color: crate::constants::PLAYER_COLOR,
-			custom_size: Some(crate::constants::PLAYER_SIZE),
-			..default()
-		},
-		Transform::from_translation(crate::constants::PLAYER_SPAWN_POSITION),
-		super::Player::default(),
-		crate::behaviors::PlayerTag,
-		crate::behaviors::Damageable {
-			health: crate::constants::PLAYER_DEFAULT_HEALTH,
-			max_health: crate::constants::PLAYER_DEFAULT_HEALTH,
-		},
-		crate::behaviors::PlayerEnergy {
-			current: crate::constants::PLAYER_DEFAULT_ENERGY,
-			max: crate::constants::PLAYER_DEFAULT_ENERGY,
-			regen_rate: crate::constants::PLAYER_ENERGY_REGEN_RATE,
-			repulsion_force: crate::constants::REPULSION_FORCE_DEFAULT,
-		},
-		crate::physics::Velocity { x: 0.0, y: 0.0 },
-		crate::physics::Grounded(false),
-		crate::physics::Collider,
-		NeedsInitialWeapons {
-			weapons: config_data.initial_weapons.clone(),
+	// Player 1 starts on the keyboard and picks up a gamepad once one is used; nothing spawns
+	// until a character has been picked
+	let spawned = spawn_player_entity(
+		&mut commands,
+		config_data,
+		&characters,
+		crate::input::PlayerController {
+			index: 0,
+			keyboard: true,
+			gamepad: None,
 		},
-	));
+		crate::constants::PLAYER_SPAWN_POSITION,
+	);
+	if spawned.is_none() {
+		return;
+	}
 
 	// Only spawn platforms if they don't exist
 	if platform_query.is_empty() {
 		spawn_platforms(&mut commands);
 	}
+}
 
-	// Only spawn UI if it doesn't exist
-	if ui_query.is_empty() {
-		super::ui::spawn_player_ui(&mut commands);
+// Pressing Join on a gamepad no player owns, or on the keyboard while no player is using it,
+// drops a new player in next to an existing one
+#[allow(clippy::too_many_arguments)]
+pub fn join_players(
+	mut commands: Commands,
+	game_config: Option<Res<crate::GameConfig>>,
+	config_assets: Res<Assets<crate::GameConfigData>>,
+	characters: crate::character::CharacterResources,
+	player_query: Query<(&Transform, &crate::input::PlayerController)>,
+	gamepads: Query<Entity, With<Gamepad>>,
+	input: crate::input::ActionInput,
+	powerup_state: Res<crate::powerups::PowerupState>,
+) {
+	// No joining from the level-up menu or before player 1 exists
+	if powerup_state.showing || player_query.is_empty() {
+		return;
 	}
+
+	let Some(game_config) = game_config else {
+		return;
+	};
+
+	let Some(config_data) = config_assets.get(&game_config.config_handle) else {
+		return;
+	};
+
+	let keyboard_free = !player_query
+		.iter()
+		.any(|(_, controller)| controller.keyboard);
+	let keyboard_join =
+		keyboard_free && input.keyboard_just_pressed(crate::input::InputAction::Join);
+
+	let gamepad = gamepads
+		.iter()
+		.filter(|entity| input.gamepad_just_pressed(*entity, crate::input::InputAction::Join))
+		.find(|entity| {
+			!player_query
+				.iter()
+				.any(|(_, controller)| controller.gamepad == Some(*entity))
+		});
+
+	if !keyboard_join && gamepad.is_none() {
+		return;
+	}
+
+	if player_query.iter().count() >= config_data.coop.max_players {
+		return;
+	}
+
+	// Lowest free player number, so a player who died and rejoins keeps their character
+	let Some(index) = (0..config_data.coop.max_players).find(|index| {
+		!player_query
+			.iter()
+			.any(|(_, controller)| controller.index == *index)
+	}) else {
+		return;
+	};
+
+	let position = player_query
+		.iter()
+		.min_by_key(|(_, controller)| controller.index)
+		.map(|(transform, _)| transform.translation + crate::constants::PLAYER_JOIN_OFFSET)
+		.unwrap_or(crate::constants::PLAYER_SPAWN_POSITION);
+
+	spawn_player_entity(
+		&mut commands,
+		config_data,
+		&characters,
+		crate::input::PlayerController {
+			index,
+			keyboard: keyboard_join,
+			gamepad: if keyboard_join { None } else { gamepad },
+		},
+		position,
+	);
+}
+
+fn spawn_player_entity(
+	commands: &mut Commands,
+	config_data: &crate::GameConfigData,
+	characters: &crate::character::CharacterResources,
+	controller: crate::input::PlayerController,
+	position: Vec3,
+) -> Option<Entity> {
+	let character = characters.for_player(controller.index)?;
+
+	let mut player = super::Player {
+		speed: character.speed,
+		jump_force: character.jump_force,
+		extra_jumps: config_data.player_movement.extra_jumps,
+		dash_charges: config_data.player_movement.dash_charges,
+		dash_cooldown: config_data.player_movement.dash_cooldown,
+		..default()
+	};
+	let mut damageable = crate::behaviors::Damageable {
+		health: character.max_health,
+		max_health: character.max_health,
+	};
+	let mut energy = crate::behaviors::PlayerEnergy {
+		current: character.max_energy,
+		max: character.max_energy,
+		regen_rate: character.energy_regen,
+		repulsion_force: crate::constants::REPULSION_FORCE_DEFAULT,
+	};
+	let mut stats = super::PlayerStats::default();
+
+	// The passive applies once up front, then again as the player levels
+	if let Some(passive) = &character.passive {
+		crate::powerups::application::apply_stat(
+			&passive.stat,
+			passive.value,
+			(&mut player, &mut damageable, &mut energy, &mut stats),
+		);
+	}
+
+	// Always-on slots start active and stay that way
+	let active_slots = config_data
+		.weapon_slots
+		.iter()
+		.filter(|slot| slot.activation == crate::SlotActivation::AlwaysOn)
+		.map(|slot| slot.name.clone())
+		.collect();
+
+	let (r, g, b) = character.color;
+	let entity = commands
+		.spawn((
+			Sprite {
+				image: characters.sprite(character),
+				color: Color::srgb(r, g, b),
+				custom_size: Some(crate::constants::PLAYER_SIZE),
+				..default()
+			},
+			Transform::from_translation(position),
+			player,
+			stats,
+			controller,
+			super::JumpState::from_config(&config_data.player_movement),
+			super::DashState::from_config(&config_data.player_movement),
+			crate::behaviors::PlayerTag,
+			damageable,
+			energy,
+			crate::physics::Velocity { x: 0.0, y: 0.0 },
+			crate::physics::Grounded(false),
+			crate::physics::WallContact::default(),
+			crate::physics::DropThrough::default(),
+			crate::physics::Collider,
+		))
+		.insert((
+			crate::experience::PlayerExperience::default(),
+			crate::weapons::WeaponInventory::default(),
+			crate::weapons::ActiveWeaponState { active_slots },
+			crate::powerups::PowerupHistory::default(),
+			crate::powerups::PowerupActions {
+				rerolls: character.rerolls,
+				skips: character.skips,
+				banishes: character.banishes,
+				..default()
+			},
+			NeedsInitialWeapons {
+				weapons: character.starting_weapons.clone(),
+			},
+		))
+		.id();
+
+	if let Some(passive) = &character.passive {
+		commands.entity(entity).insert(passive.clone());
+	}
+
+	Some(entity)
 }
 
 pub fn spawn_initial_weapon(
 	mut commands: Commands,
-	mut player_query: Query<(Entity, &NeedsInitialWeapons)>,
+	mut player_query: Query<(
+		Entity,
+		&NeedsInitialWeapons,
+		&mut crate::weapons::WeaponInventory,
+	)>,
 	weapon_registry: Option<Res<crate::weapons::WeaponRegistry>>,
 	weapon_data_assets: Res<Assets<crate::weapons::WeaponData>>,
-	mut weapon_inventory: Option<ResMut<crate::weapons::WeaponInventory>>,
 ) {
 	let Some(registry) = weapon_registry else {
 		return;
 	};
 
-	for (entity, needs_weapons) in player_query.iter_mut() {
+	for (entity, needs_weapons, mut inventory) in player_query.iter_mut() {
 		// Spawn each initial weapon
 		for weapon_config in &needs_weapons.weapons {
 			if let Some(handle) = registry.get(&weapon_config.weapon_id) {
@@ -119,16 +336,15 @@ pub fn spawn_initial_weapon(
 						weapon_data,
 						weapon_config.level,
 						&weapon_config.weapon_id,
+						entity,
 					);
 
 					// Add to inventory
-					if let Some(inventory) = &mut weapon_inventory {
-						if !weapon_entities.is_empty() {
-							inventory.weapons.insert(
-								weapon_config.weapon_id.clone(),
-								(weapon_entities[0], weapon_config.level),
-							);
-						}
+					if !weapon_entities.is_empty() {
+						inventory.weapons.insert(
+							weapon_config.weapon_id.clone(),
+							(weapon_entities[0], weapon_config.level),
+						);
 					}
 				}
 			}
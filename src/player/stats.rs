@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+pub fn regenerate_health(
+	mut player_query: Query<(&super::PlayerStats, &mut crate::behaviors::Damageable)>,
+	time: Res<Time<Virtual>>,
+) {
+	for (stats, mut damageable) in player_query.iter_mut() {
+		if stats.health_regen > 0.0 && damageable.health > 0.0 {
+			damageable.health = (damageable.health + stats.health_regen * time.delta_secs())
+				.min(damageable.max_health);
+		}
+	}
+}
+
+// Heal each player by a share of the damage their weapons dealt this frame
+pub fn apply_lifesteal(
+	mut damage_events: MessageReader<crate::combat::DamageDealtEvent>,
+	mut player_query: Query<(&super::PlayerStats, &mut crate::behaviors::Damageable)>,
+) {
+	for event in damage_events.read() {
+		let Ok((stats, mut damageable)) = player_query.get_mut(event.player) else {
+			continue;
+		};
+
+		if damageable.health > 0.0 {
+			damageable.health =
+				(damageable.health + event.amount * stats.lifesteal).min(damageable.max_health);
+		}
+	}
+}
@@ -3,10 +3,32 @@ use bevy::prelude::*;
 type PlayerStatsQuery<'w, 's> = Query<
 	'w,
 	's,
-	(&'static super::Player, &'static crate::behaviors::Damageable),
-	Or<(Changed<super::Player>, Changed<crate::behaviors::Damageable>)>,
+	(
+		&'static super::Player,
+		&'static crate::behaviors::Damageable,
+		&'static crate::input::PlayerController,
+	),
 >;
 
+type ChangedPlayerStatsQuery<'w, 's> = Query<
+	'w,
+	's,
+	(),
+	(
+		With<super::Player>,
+		Or<(
+			Changed<super::Player>,
+			Changed<crate::behaviors::Damageable>,
+		)>,
+	),
+>;
+
+/// Ties a HUD element to the player with the matching controller index.
+#[derive(Component)]
+pub struct PlayerHud {
+	pub player: usize,
+}
+
 #[derive(Component)]
 pub struct PlayerStatsText;
 
@@ -28,14 +50,42 @@ pub struct EnergyBarForeground;
 #[derive(Component)]
 pub struct EnergyText;
 
-pub fn spawn_player_ui(commands: &mut Commands) {
+// Gives every player their own stats line, XP bar and energy bar, stacked by player index,
+// and removes the HUD of players who are gone
+pub fn sync_player_hud(
+	mut commands: Commands,
+	player_query: Query<&crate::input::PlayerController, With<super::Player>>,
+	hud_query: Query<(Entity, &PlayerHud)>,
+) {
+	for (entity, hud) in hud_query.iter() {
+		if !player_query
+			.iter()
+			.any(|controller| controller.index == hud.player)
+		{
+			commands.entity(entity).despawn();
+		}
+	}
+
+	for controller in player_query.iter() {
+		if !hud_query
+			.iter()
+			.any(|(_, hud)| hud.player == controller.index)
+		{
+			spawn_player_ui(&mut commands, controller.index);
+		}
+	}
+}
+
+fn spawn_player_ui(commands: &mut Commands, player: usize) {
 	use crate::constants::*;
 
+	let offset = player as f32 * PLAYER_HUD_SPACING;
+
 	commands.spawn((
 		Text::new("Health: 100/100 | Level: 1"),
 		Node {
 			position_type: PositionType::Absolute,
-			top: Val::Px(UI_MARGIN),
+			top: Val::Px(UI_MARGIN + offset),
 			left: Val::Px(UI_MARGIN),
 			..default()
 		},
@@ -44,13 +94,14 @@ pub fn spawn_player_ui(commands: &mut Commands) {
 			font_size: UI_FONT_SIZE_NORMAL,
 			..default()
 		},
+		PlayerHud { player },
 		PlayerStatsText,
 	));
 
 	commands.spawn((
 		Node {
 			position_type: PositionType::Absolute,
-			top: Val::Px(XP_BAR_TOP),
+			top: Val::Px(XP_BAR_TOP + offset),
 			left: Val::Px(UI_MARGIN),
 			width: Val::Px(XP_BAR_WIDTH),
 			height: Val::Px(XP_BAR_HEIGHT),
@@ -58,13 +109,14 @@ pub fn spawn_player_ui(commands: &mut Commands) {
 		},
 		BackgroundColor(XP_BAR_COLOR_BG),
 		ZIndex(0),
+		PlayerHud { player },
 		XPBarBackground,
 	));
 
 	commands.spawn((
 		Node {
 			position_type: PositionType::Absolute,
-			top: Val::Px(XP_BAR_TOP),
+			top: Val::Px(XP_BAR_TOP + offset),
 			left: Val::Px(UI_MARGIN),
 			width: Val::Px(0.0),
 			height: Val::Px(XP_BAR_HEIGHT),
@@ -72,6 +124,7 @@ pub fn spawn_player_ui(commands: &mut Commands) {
 		},
 		BackgroundColor(XP_BAR_COLOR_FG),
 		ZIndex(1),
+		PlayerHud { player },
 		XPBarForeground,
 	));
 
@@ -79,7 +132,7 @@ pub fn spawn_player_ui(commands: &mut Commands) {
 		Text::new("XP: 0/100"),
 		Node {
 			position_type: PositionType::Absolute,
-			top: Val::Px(XP_BAR_TOP + 2.0),
+			top: Val::Px(XP_BAR_TOP + offset + 2.0),
 			left: Val::Px(UI_MARGIN + 5.0),
 			..default()
 		},
@@ -89,13 +142,14 @@ pub fn spawn_player_ui(commands: &mut Commands) {
 			..default()
 		},
 		ZIndex(2),
+		PlayerHud { player },
 		XPText,
 	));
 
 	commands.spawn((
 		Node {
 			position_type: PositionType::Absolute,
-			top: Val::Px(ENERGY_BAR_TOP),
+			top: Val::Px(ENERGY_BAR_TOP + offset),
 			left: Val::Px(UI_MARGIN),
 			width: Val::Px(ENERGY_BAR_WIDTH),
 			height: Val::Px(ENERGY_BAR_HEIGHT),
@@ -103,13 +157,14 @@ pub fn spawn_player_ui(commands: &mut Commands) {
 		},
 		BackgroundColor(ENERGY_BAR_COLOR_BG),
 		ZIndex(0),
+		PlayerHud { player },
 		EnergyBarBackground,
 	));
 
 	commands.spawn((
 		Node {
 			position_type: PositionType::Absolute,
-			top: Val::Px(ENERGY_BAR_TOP),
+			top: Val::Px(ENERGY_BAR_TOP + offset),
 			left: Val::Px(UI_MARGIN),
 			width: Val::Px(ENERGY_BAR_WIDTH),
 			height: Val::Px(ENERGY_BAR_HEIGHT),
@@ -117,6 +172,7 @@ pub fn spawn_player_ui(commands: &mut Commands) {
 		},
 		BackgroundColor(ENERGY_BAR_COLOR_FG),
 		ZIndex(1),
+		PlayerHud { player },
 		EnergyBarForeground,
 	));
 
@@ -124,7 +180,7 @@ pub fn spawn_player_ui(commands: &mut Commands) {
 		Text::new("Energy: 100/100"),
 		Node {
 			position_type: PositionType::Absolute,
-			top: Val::Px(ENERGY_BAR_TOP + 2.0),
+			top: Val::Px(ENERGY_BAR_TOP + offset + 2.0),
 			left: Val::Px(UI_MARGIN + 5.0),
 			..default()
 		},
@@ -134,73 +190,106 @@ pub fn spawn_player_ui(commands: &mut Commands) {
 			..default()
 		},
 		ZIndex(2),
+		PlayerHud { player },
 		EnergyText,
 	));
 }
 
 pub fn update_player_stats_display(
 	player_query: PlayerStatsQuery,
-	mut text_query: Query<&mut Text, With<PlayerStatsText>>,
+	changed_query: ChangedPlayerStatsQuery,
+	new_text_query: Query<(), Added<PlayerStatsText>>,
+	mut removed_players: RemovedComponents<super::Player>,
+	mut text_query: Query<(&mut Text, &PlayerHud), With<PlayerStatsText>>,
 ) {
-	if let Ok((player, damageable)) = player_query.single() {
-		if let Ok(mut text) = text_query.single_mut() {
-			**text = format!(
-				"Health: {:.0}/{:.0} | Level: {}",
-				damageable.health, damageable.max_health, player.level
-			);
-		}
+	// Rebuild when any player's stats change, a player joins or a player leaves
+	let players_left = removed_players.read().count() > 0;
+	if changed_query.is_empty() && new_text_query.is_empty() && !players_left {
+		return;
+	}
+
+	// Lines are labeled once a second player has joined
+	let player_count = player_query.iter().count();
+	for (mut text, hud) in text_query.iter_mut() {
+		let Some((player, damageable, _)) = player_query
+			.iter()
+			.find(|(_, _, controller)| controller.index == hud.player)
+		else {
+			continue;
+		};
+
+		let stats = format!(
+			"Health: {:.0}/{:.0} | Level: {} | Pickup: {:.0} | XP: x{:.2}",
+			damageable.health,
+			damageable.max_health,
+			player.level,
+			player.pickup_radius,
+			player.xp_multiplier
+		);
+		**text = if player_count > 1 {
+			format!("P{} {}", hud.player + 1, stats)
+		} else {
+			stats
+		};
 	}
 }
 
 pub fn update_xp_bar(
-	player_xp: Res<crate::experience::PlayerExperience>,
-	mut xp_bar_query: Query<&mut Node, With<XPBarForeground>>,
-	mut xp_text_query: Query<&mut Text, With<XPText>>,
+	player_query: Query<(
+		&crate::experience::PlayerExperience,
+		&crate::input::PlayerController,
+	)>,
+	mut xp_bar_query: Query<(&mut Node, &PlayerHud), With<XPBarForeground>>,
+	mut xp_text_query: Query<(&mut Text, &PlayerHud), With<XPText>>,
 ) {
-	// Only update if UI exists
-	let Ok(mut node) = xp_bar_query.single_mut() else {
-		return;
-	};
+	for (player_xp, controller) in player_query.iter() {
+		// Update XP bar width
+		let xp_percent =
+			(player_xp.current_xp as f32 / player_xp.xp_to_next_level as f32).clamp(0.0, 1.0);
+		for (mut node, _) in xp_bar_query
+			.iter_mut()
+			.filter(|(_, hud)| hud.player == controller.index)
+		{
+			node.width = Val::Px(crate::constants::XP_BAR_WIDTH * xp_percent);
+		}
 
-	let Ok(mut text) = xp_text_query.single_mut() else {
-		return;
-	};
-
-	// Update XP bar width
-	let xp_percent =
-		(player_xp.current_xp as f32 / player_xp.xp_to_next_level as f32).clamp(0.0, 1.0);
-	let new_width = crate::constants::XP_BAR_WIDTH * xp_percent;
-	node.width = Val::Px(new_width);
-
-	// Update XP text
-	**text = format!(
-		"XP: {}/{}",
-		player_xp.current_xp, player_xp.xp_to_next_level
-	);
+		// Update XP text
+		for (mut text, _) in xp_text_query
+			.iter_mut()
+			.filter(|(_, hud)| hud.player == controller.index)
+		{
+			**text = format!(
+				"XP: {}/{}",
+				player_xp.current_xp, player_xp.xp_to_next_level
+			);
+		}
+	}
 }
 
 pub fn update_energy_bar(
-	player_query: Query<&crate::behaviors::PlayerEnergy, (With<super::Player>, Changed<crate::behaviors::PlayerEnergy>)>,
-	mut energy_bar_query: Query<&mut Node, With<EnergyBarForeground>>,
-	mut energy_text_query: Query<&mut Text, With<EnergyText>>,
+	player_query: Query<(
+		&crate::behaviors::PlayerEnergy,
+		&crate::input::PlayerController,
+	)>,
+	mut energy_bar_query: Query<(&mut Node, &PlayerHud), With<EnergyBarForeground>>,
+	mut energy_text_query: Query<(&mut Text, &PlayerHud), With<EnergyText>>,
 ) {
-	// Only update if UI exists
-	let Ok(mut node) = energy_bar_query.single_mut() else {
-		return;
-	};
-
-	let Ok(mut text) = energy_text_query.single_mut() else {
-		return;
-	};
-
-	// Only update if energy changed
-	if let Ok(energy) = player_query.single() {
+	for (energy, controller) in player_query.iter() {
 		// Update energy bar width
 		let energy_percent = (energy.current / energy.max).clamp(0.0, 1.0);
-		let new_width = crate::constants::ENERGY_BAR_WIDTH * energy_percent;
-		node.width = Val::Px(new_width);
+		for (mut node, _) in energy_bar_query
+			.iter_mut()
+			.filter(|(_, hud)| hud.player == controller.index)
+		{
+			node.width = Val::Px(crate::constants::ENERGY_BAR_WIDTH * energy_percent);
+		}
 
 		// Update energy text
-		**text = format!("Energy: {:.0}/{:.0}", energy.current, energy.max);
+		for (mut text, _) in energy_text_query
+			.iter_mut()
+			.filter(|(_, hud)| hud.player == controller.index)
+		{
+			**text = format!("Energy: {:.0}/{:.0}", energy.current, energy.max);
+		}
 	}
 }
@@ -4,15 +4,18 @@ pub fn apply_powerup(
 	powerup_def: &crate::PowerupDefinition,
 	commands: &mut Commands,
 	player_stats: (
+		Entity,
 		&mut crate::player::Player,
 		&mut crate::behaviors::Damageable,
 		&mut crate::behaviors::PlayerEnergy,
+		&mut crate::player::PlayerStats,
 	),
 	weapon_resources: &super::WeaponResources,
 	weapon_inventory: &mut crate::weapons::WeaponInventory,
 	weapon_level_query: &mut Query<&mut crate::behaviors::WeaponLevel>,
+	powerup_history: &mut super::PowerupHistory,
 ) {
-	let (player, player_damageable, player_energy) = player_stats;
+	let (owner, player, player_damageable, player_energy, stats) = player_stats;
 	match powerup_def {
 		crate::PowerupDefinition::Weapon(weapon_id) => {
 			// Check if player already owns this weapon
@@ -36,6 +39,7 @@ pub fn apply_powerup(
 								weapon_data,
 								1,
 								weapon_id,
+								owner,
 							);
 							if !entities.is_empty() {
 								weapon_inventory
@@ -47,23 +51,238 @@ pub fn apply_powerup(
 				}
 			}
 		}
-		crate::PowerupDefinition::StatBoost(boost) => match boost.stat {
-			crate::StatType::Speed => {
-				player.speed += boost.value;
-			}
-			crate::StatType::JumpForce => {
-				player.jump_force += boost.value;
-			}
-			crate::StatType::MaxHealth => {
-				player_damageable.max_health += boost.value;
-				player_damageable.health = player_damageable.max_health;
-			}
-			crate::StatType::EnergyRegen => {
-				player_energy.regen_rate += boost.value;
-			}
-			crate::StatType::RepulsionForce => {
-				player_energy.repulsion_force += boost.value;
+		crate::PowerupDefinition::Evolution(recipe) => {
+			let Some((base_entity, _level)) = weapon_inventory.weapons.get(&recipe.base_weapon)
+			else {
+				return;
+			};
+			let base_entity = *base_entity;
+
+			// Spawn the evolved weapon; it takes over the base weapon's slot once spawned
+			if let Some(registry) = weapon_resources.registry.as_ref() {
+				if let Some(handle) = registry.get(&recipe.evolved_weapon) {
+					if let Some(weapon_data) = weapon_resources.assets.get(handle) {
+						let entities = crate::weapons::spawn_entity_from_data(
+							commands,
+							weapon_data,
+							1,
+							&recipe.evolved_weapon,
+							owner,
+						);
+						if let Some(&evolved_entity) = entities.first() {
+							commands.entity(evolved_entity).insert(
+								crate::weapons::PendingEvolution {
+									base_entity,
+									base_weapon_id: recipe.base_weapon.clone(),
+								},
+							);
+							weapon_inventory.weapons.remove(&recipe.base_weapon);
+							weapon_inventory
+								.weapons
+								.insert(recipe.evolved_weapon.clone(), (evolved_entity, 1));
+						}
+					}
+				}
 			}
-		},
+		}
+		crate::PowerupDefinition::StatBoost(boost) => {
+			*powerup_history
+				.stat_boosts
+				.entry(boost.name.clone())
+				.or_insert(0) += 1;
+
+			apply_stat(
+				&boost.stat,
+				boost.value,
+				(player, player_damageable, player_energy, stats),
+			);
+		}
 	}
 }
+
+// Raise one player stat, shared by stat boost powerups and character passives
+pub fn apply_stat(
+	stat: &crate::StatType,
+	value: f32,
+	player_stats: (
+		&mut crate::player::Player,
+		&mut crate::behaviors::Damageable,
+		&mut crate::behaviors::PlayerEnergy,
+		&mut crate::player::PlayerStats,
+	),
+) {
+	let (player, player_damageable, player_energy, stats) = player_stats;
+	match stat {
+		crate::StatType::Speed => {
+			player.speed += value;
+		}
+		crate::StatType::JumpForce => {
+			player.jump_force += value;
+		}
+		crate::StatType::MaxHealth => {
+			player_damageable.max_health += value;
+			player_damageable.health = player_damageable.max_health;
+		}
+		crate::StatType::EnergyRegen => {
+			player_energy.regen_rate += value;
+		}
+		crate::StatType::RepulsionForce => {
+			player_energy.repulsion_force += value;
+		}
+		crate::StatType::ExtraJumps => {
+			player.extra_jumps += value.max(0.0) as u32;
+		}
+		crate::StatType::DashCharges => {
+			player.dash_charges += value.max(0.0) as u32;
+		}
+		crate::StatType::DashCooldown => {
+			player.dash_cooldown =
+				(player.dash_cooldown - value).max(crate::constants::PLAYER_DASH_MIN_COOLDOWN);
+		}
+		crate::StatType::PickupRadius => {
+			player.pickup_radius += value;
+		}
+		crate::StatType::XpMultiplier => {
+			player.xp_multiplier += value;
+		}
+		crate::StatType::Might => {
+			stats.might += value;
+		}
+		crate::StatType::Area => {
+			stats.area += value;
+		}
+		crate::StatType::Cooldown => {
+			stats.cooldown += value;
+		}
+		crate::StatType::Amount => {
+			stats.amount += value.max(0.0) as u32;
+		}
+		crate::StatType::Armor => {
+			stats.armor += value;
+		}
+		crate::StatType::Luck => {
+			stats.luck += value;
+		}
+		crate::StatType::Lifesteal => {
+			stats.lifesteal += value;
+		}
+		crate::StatType::HealthRegen => {
+			stats.health_regen += value;
+		}
+	}
+}
+
+// Evolution recipes whose base weapon level and requirement are satisfied
+pub fn ready_evolutions<'a>(
+	recipes: &'a [crate::EvolutionRecipe],
+	weapon_inventory: &crate::weapons::WeaponInventory,
+	powerup_history: &super::PowerupHistory,
+) -> Vec<&'a crate::EvolutionRecipe> {
+	recipes
+		.iter()
+		.filter(|recipe| {
+			let base_ready = weapon_inventory
+				.weapons
+				.get(&recipe.base_weapon)
+				.is_some_and(|(_entity, level)| *level >= recipe.base_level);
+
+			let requirement_met = match &recipe.requires {
+				crate::EvolutionRequirement::StatBoost(name) => {
+					powerup_history.stat_boosts.contains_key(name)
+				}
+				crate::EvolutionRequirement::Weapon(weapon_id) => {
+					weapon_inventory.weapons.contains_key(weapon_id)
+				}
+			};
+
+			base_ready
+				&& requirement_met
+				&& !weapon_inventory
+					.weapons
+					.contains_key(&recipe.evolved_weapon)
+		})
+		.collect()
+}
+
+// Owns the weapon or has taken the stat boost with this ID
+fn has_powerup(
+	id: &str,
+	weapon_inventory: &crate::weapons::WeaponInventory,
+	powerup_history: &super::PowerupHistory,
+) -> bool {
+	weapon_inventory.weapons.contains_key(id) || powerup_history.stat_boosts.contains_key(id)
+}
+
+// Weapon level, or times a stat boost has been taken
+fn stacks_taken(
+	powerup: &crate::PowerupDefinition,
+	weapon_inventory: &crate::weapons::WeaponInventory,
+	powerup_history: &super::PowerupHistory,
+) -> u32 {
+	match powerup {
+		crate::PowerupDefinition::Weapon(id) => weapon_inventory
+			.weapons
+			.get(id)
+			.map_or(0, |(_entity, level)| *level),
+		crate::PowerupDefinition::StatBoost(boost) => powerup_history
+			.stat_boosts
+			.get(&boost.name)
+			.copied()
+			.unwrap_or(0),
+		crate::PowerupDefinition::Evolution(_) => 0,
+	}
+}
+
+// Pool entries the player can be offered right now
+pub fn available_powerups<'a>(
+	config_data: &'a crate::GameConfigData,
+	weapon_resources: &super::WeaponResources,
+	weapon_inventory: &crate::weapons::WeaponInventory,
+	powerup_history: &super::PowerupHistory,
+	powerup_actions: &super::PowerupActions,
+) -> Vec<&'a crate::PowerupPoolEntry> {
+	config_data
+		.powerup_pool
+		.iter()
+		.filter(|entry| {
+			let under_max_stacks = entry.max_stacks.is_none_or(|max_stacks| {
+				stacks_taken(&entry.powerup, weapon_inventory, powerup_history) < max_stacks
+			});
+
+			let requirements_met = entry.requires.iter().all(|requirement| match requirement {
+				crate::PowerupRequirement::Powerup(id) => {
+					has_powerup(id, weapon_inventory, powerup_history)
+				}
+				crate::PowerupRequirement::WeaponLevel(weapon_id, level) => weapon_inventory
+					.weapons
+					.get(weapon_id)
+					.is_some_and(|(_entity, owned_level)| owned_level >= level),
+			});
+
+			// A weapon that was evolved away can't be picked up again next to its evolution
+			let evolved = match &entry.powerup {
+				crate::PowerupDefinition::Weapon(id) => {
+					config_data.evolutions.iter().any(|recipe| {
+						recipe.base_weapon == *id
+							&& weapon_inventory
+								.weapons
+								.contains_key(&recipe.evolved_weapon)
+					})
+				}
+				_ => false,
+			};
+
+			let excluded = evolved
+				|| entry
+					.excludes
+					.iter()
+					.any(|id| has_powerup(id, weapon_inventory, powerup_history));
+
+			!super::ui::is_weapon_maxed(&entry.powerup, weapon_resources, weapon_inventory)
+				&& !powerup_actions.banished.contains(&entry.powerup)
+				&& under_max_stacks
+				&& requirements_met
+				&& !excluded
+		})
+		.collect()
+}
@@ -1,4 +1,5 @@
 use bevy::{ecs::system::SystemParam, prelude::*};
+use serde::Deserialize;
 
 pub mod application;
 pub mod ui;
@@ -17,6 +18,9 @@ impl Plugin for PowerupsPlugin {
 			showing: false,
 			options: vec![],
 			selected_index: 0,
+			player: None,
+			pending: std::collections::VecDeque::new(),
+			banishing: false,
 		})
 		.add_systems(
 			Update,
@@ -34,4 +38,35 @@ pub struct PowerupState {
 	pub showing: bool,
 	pub options: Vec<crate::PowerupDefinition>,
 	pub selected_index: usize,
+	pub player: Option<Entity>, // Player choosing from the open menu
+	pub pending: std::collections::VecDeque<Entity>, // Players waiting for their own menu
+	pub banishing: bool,        // The next powerup picked is banished instead of taken
+}
+
+/// Stat boosts a player has taken, used by evolution requirements.
+#[derive(Component, Default)]
+pub struct PowerupHistory {
+	pub stat_boosts: std::collections::HashMap<String, u32>, // stat boost name -> times taken
+}
+
+/// Reroll, skip and banish uses a player has left, from their character and level rewards.
+#[derive(Component, Default)]
+pub struct PowerupActions {
+	pub rerolls: u32,
+	pub skips: u32,
+	pub banishes: u32,
+	pub banished: Vec<crate::PowerupDefinition>, // Never offered to this player again
+}
+
+/// What skipping a level-up menu gives instead of a powerup.
+#[derive(Deserialize, Clone)]
+pub enum SkipReward {
+	Heal(f32),
+	Xp(u32),
+}
+
+impl Default for SkipReward {
+	fn default() -> Self {
+		SkipReward::Heal(crate::constants::POWERUP_SKIP_HEAL)
+	}
 }
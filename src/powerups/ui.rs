@@ -4,9 +4,18 @@ use rand::seq::SliceRandom;
 #[derive(Component)]
 pub struct PowerupUIContainer;
 
+/// What a level-up menu button does when chosen.
+#[derive(Clone)]
+pub enum PowerupChoice {
+	Powerup(crate::PowerupDefinition),
+	Reroll,
+	Skip,
+	Banish, // Toggles banish mode
+}
+
 #[derive(Component)]
 pub struct PowerupButton {
-	pub powerup_def: crate::PowerupDefinition,
+	pub choice: PowerupChoice,
 	pub index: usize,
 }
 
@@ -17,12 +26,6 @@ pub struct PowerupUIState<'w, 's> {
 	pub time: ResMut<'w, Time<Virtual>>,
 }
 
-#[derive(SystemParam)]
-pub struct InputState<'w, 's> {
-	pub gamepads: Query<'w, 's, &'static Gamepad>,
-	pub keyboard: Res<'w, ButtonInput<KeyCode>>,
-}
-
 fn get_powerup_name(
 	powerup: &crate::PowerupDefinition,
 	weapon_resources: &super::WeaponResources,
@@ -46,6 +49,17 @@ fn get_powerup_name(
 			}
 		}
 		crate::PowerupDefinition::StatBoost(data) => data.name.clone(),
+		crate::PowerupDefinition::Evolution(recipe) => {
+			let evolved_name = weapon_resources
+				.registry
+				.as_ref()
+				.and_then(|r| r.get(&recipe.evolved_weapon))
+				.and_then(|h| weapon_resources.assets.get(h))
+				.map(|w| w.name.clone())
+				.unwrap_or_else(|| recipe.evolved_weapon.clone());
+
+			format!("EVOLUTION: {}", evolved_name)
+		}
 	}
 }
 
@@ -65,189 +79,515 @@ fn get_powerup_description(
 				.unwrap_or_else(|| format!("Unknown weapon: {}", id));
 
 			// Show upgrade effects if owned
-			if let Some((_entity, _level)) = weapon_inventory.weapons.get(id) {
-				format!(
-					"{} | Upgrade: +20% damage, -10% cooldown, +15% effects",
-					base_desc
-				)
+			if let Some((_entity, level)) = weapon_inventory.weapons.get(id) {
+				// Weapons with a level table describe their next level explicitly
+				let next_level_desc = weapon_resources
+					.registry
+					.as_ref()
+					.and_then(|r| r.get(id))
+					.and_then(|h| weapon_resources.assets.get(h))
+					.and_then(|w| w.levels.get(*level as usize - 1))
+					.map(|next| next.description.clone());
+
+				match next_level_desc {
+					Some(next_desc) => {
+						format!("{} | Level {}: {}", base_desc, level + 1, next_desc)
+					}
+					None => format!(
+						"{} | Upgrade: +20% damage, -10% cooldown, +15% effects",
+						base_desc
+					),
+				}
 			} else {
 				base_desc
 			}
 		}
 		crate::PowerupDefinition::StatBoost(data) => data.description.clone(),
+		crate::PowerupDefinition::Evolution(recipe) => {
+			let base_name = weapon_resources
+				.registry
+				.as_ref()
+				.and_then(|r| r.get(&recipe.base_weapon))
+				.and_then(|h| weapon_resources.assets.get(h))
+				.map(|w| w.name.clone())
+				.unwrap_or_else(|| recipe.base_weapon.clone());
+			let evolved_desc = weapon_resources
+				.registry
+				.as_ref()
+				.and_then(|r| r.get(&recipe.evolved_weapon))
+				.and_then(|h| weapon_resources.assets.get(h))
+				.map(|w| w.description.clone())
+				.unwrap_or_else(|| format!("Unknown weapon: {}", recipe.evolved_weapon));
+
+			format!("Replaces {} | {}", base_name, evolved_desc)
+		}
 	}
 }
 
+pub(crate) fn is_weapon_maxed(
+	powerup: &crate::PowerupDefinition,
+	weapon_resources: &super::WeaponResources,
+	weapon_inventory: &crate::weapons::WeaponInventory,
+) -> bool {
+	let crate::PowerupDefinition::Weapon(id) = powerup else {
+		return false;
+	};
+	let Some((_entity, level)) = weapon_inventory.weapons.get(id) else {
+		return false;
+	};
+
+	weapon_resources
+		.registry
+		.as_ref()
+		.and_then(|r| r.get(id))
+		.and_then(|h| weapon_resources.assets.get(h))
+		.and_then(|w| w.max_level())
+		.is_some_and(|max_level| *level >= max_level)
+}
+
 pub fn cleanup_powerup_ui(commands: &mut Commands, ui_state: &mut PowerupUIState) {
 	for entity in ui_state.ui_query.iter() {
 		commands.entity(entity).despawn();
 	}
 	ui_state.state.showing = false;
 	ui_state.state.options.clear();
+	ui_state.state.player = None;
+	ui_state.state.banishing = false;
 	ui_state.time.unpause();
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn handle_level_up(
-	mut commands: Commands,
-	mut level_up_events: MessageReader<crate::experience::LevelUpEvent>,
-	mut powerup_state: ResMut<super::PowerupState>,
-	mut time: ResMut<Time<Virtual>>,
-	game_config: Option<Res<crate::GameConfig>>,
-	config_assets: Res<Assets<crate::GameConfigData>>,
-	weapon_resources: super::WeaponResources,
-	weapon_inventory: Res<crate::weapons::WeaponInventory>,
+// Random powerups from the pool, skipping maxed-out weapons and anything banished
+fn roll_powerup_options(
+	config_data: &crate::GameConfigData,
+	player: (&crate::player::Player, &crate::player::PlayerStats),
+	weapon_inventory: &crate::weapons::WeaponInventory,
+	powerup_history: &super::PowerupHistory,
+	powerup_actions: &super::PowerupActions,
+	weapon_resources: &super::WeaponResources,
+) -> Vec<crate::PowerupDefinition> {
+	let (player, stats) = player;
+	let mut rng = rand::thread_rng();
+	let available = super::application::available_powerups(
+		config_data,
+		weapon_resources,
+		weapon_inventory,
+		powerup_history,
+		powerup_actions,
+	);
+
+	// Weighted draw without repeats
+	let mut options: Vec<crate::PowerupDefinition> = available
+		.choose_multiple_weighted(&mut rng, player.powerup_options, |entry| {
+			draw_weight(entry, stats)
+		})
+		.map(|chosen| chosen.map(|entry| entry.powerup.clone()).collect())
+		.unwrap_or_default();
+
+	// A ready evolution takes the first card
+	let evolutions = super::application::ready_evolutions(
+		&config_data.evolutions,
+		weapon_inventory,
+		powerup_history,
+	);
+	if let Some(recipe) = evolutions.choose(&mut rng) {
+		options.insert(0, crate::PowerupDefinition::Evolution((*recipe).clone()));
+		options.truncate(player.powerup_options);
+	}
+
+	options
+}
+
+// Rarity scales each entry's weight and luck favors rarer ones
+fn draw_weight(entry: &crate::PowerupPoolEntry, stats: &crate::player::PlayerStats) -> f32 {
+	let luck = if entry.rarity == crate::PowerupRarity::Common {
+		1.0
+	} else {
+		stats.luck_multiplier()
+	};
+	entry.weight * entry.rarity.weight() * luck
+}
+
+// Swaps a banished card for a fresh draw that isn't already on offer, or drops it when the
+// pool has nothing else left
+fn replace_banished_option(
+	options: &mut Vec<crate::PowerupDefinition>,
+	banished: &crate::PowerupDefinition,
+	stats: &crate::player::PlayerStats,
+	config_data: &crate::GameConfigData,
+	inventory: (&crate::weapons::WeaponInventory, &super::WeaponResources),
+	powerup_history: &super::PowerupHistory,
+	powerup_actions: &super::PowerupActions,
 ) {
-	for _ in level_up_events.read() {
-		if powerup_state.showing {
-			continue;
+	let (weapon_inventory, weapon_resources) = inventory;
+	let Some(position) = options.iter().position(|option| option == banished) else {
+		return;
+	};
+
+	let available: Vec<_> = super::application::available_powerups(
+		config_data,
+		weapon_resources,
+		weapon_inventory,
+		powerup_history,
+		powerup_actions,
+	)
+	.into_iter()
+	.filter(|entry| !options.contains(&entry.powerup))
+	.collect();
+
+	match available.choose_weighted(&mut rand::thread_rng(), |entry| draw_weight(entry, stats)) {
+		Ok(entry) => options[position] = entry.powerup.clone(),
+		Err(_) => {
+			options.remove(position);
 		}
+	}
+}
 
-		let Some(game_config) = game_config.as_ref() else {
-			continue;
-		};
+fn menu_title(
+	player_count: usize,
+	controller: &crate::input::PlayerController,
+	banishing: bool,
+) -> String {
+	let prompt = if banishing {
+		"Choose a Powerup to Banish:"
+	} else {
+		"Choose a Powerup:"
+	};
+
+	// Title names the player when several are playing
+	if player_count > 1 {
+		format!("PLAYER {} LEVEL UP! {}", controller.index + 1, prompt)
+	} else {
+		format!("LEVEL UP! {}", prompt)
+	}
+}
 
-		let Some(config_data) = config_assets.get(&game_config.config_handle) else {
-			continue;
-		};
+// Labels for the reroll, skip and banish buttons the player can currently use
+fn action_choices(
+	options: &[crate::PowerupDefinition],
+	powerup_actions: &super::PowerupActions,
+	skip_reward: &super::SkipReward,
+	banishing: bool,
+) -> Vec<(PowerupChoice, String)> {
+	let mut choices = Vec::new();
+
+	if powerup_actions.rerolls > 0 {
+		choices.push((
+			PowerupChoice::Reroll,
+			format!("Reroll ({} left)", powerup_actions.rerolls),
+		));
+	}
 
-		// Generate random powerup options from the pool
-		let mut rng = rand::thread_rng();
-		let options: Vec<crate::PowerupDefinition> = config_data
-			.powerup_pool
-			.choose_multiple(&mut rng, crate::constants::POWERUP_OPTIONS_COUNT)
-			.cloned()
-			.collect();
+	// Skipping is free when there's nothing left to offer
+	let skip_label = match skip_reward {
+		super::SkipReward::Heal(amount) => format!("Skip and heal {:.0}", amount),
+		super::SkipReward::Xp(amount) => format!("Skip and gain {} XP", amount),
+	};
+	if options.is_empty() {
+		choices.push((PowerupChoice::Skip, skip_label));
+	} else if powerup_actions.skips > 0 {
+		choices.push((
+			PowerupChoice::Skip,
+			format!("{} ({} left)", skip_label, powerup_actions.skips),
+		));
+	}
 
-		powerup_state.showing = true;
-		powerup_state.options = options.clone();
-		powerup_state.selected_index = 0;
+	if banishing {
+		choices.push((PowerupChoice::Banish, "Cancel Banish".to_string()));
+	} else if powerup_actions.banishes > 0 && !options.is_empty() {
+		choices.push((
+			PowerupChoice::Banish,
+			format!("Banish ({} left)", powerup_actions.banishes),
+		));
+	}
 
-		// Pause the game
-		time.pause();
+	choices
+}
 
-		// Create UI overlay
-		let container = commands
+#[allow(clippy::too_many_arguments)]
+fn spawn_powerup_menu(
+	commands: &mut Commands,
+	title_text: String,
+	options: &[crate::PowerupDefinition],
+	powerup_actions: &super::PowerupActions,
+	config_data: &crate::GameConfigData,
+	banishing: bool,
+	weapon_resources: &super::WeaponResources,
+	weapon_inventory: &crate::weapons::WeaponInventory,
+) {
+	// Create UI overlay
+	let container = commands
+		.spawn((
+			Node {
+				width: Val::Percent(100.0),
+				height: Val::Percent(100.0),
+				position_type: PositionType::Absolute,
+				justify_content: JustifyContent::Center,
+				align_items: AlignItems::Center,
+				..default()
+			},
+			BackgroundColor(Color::srgba(
+				0.0,
+				0.0,
+				0.0,
+				crate::constants::POWERUP_OVERLAY_ALPHA,
+			)),
+			PowerupUIContainer,
+		))
+		.id();
+
+	let button_container = commands
+		.spawn(Node {
+			flex_direction: FlexDirection::Column,
+			row_gap: Val::Px(crate::constants::POWERUP_BUTTON_GAP),
+			..default()
+		})
+		.id();
+
+	commands.entity(container).add_child(button_container);
+
+	let title = commands
+		.spawn((
+			Text::new(title_text),
+			TextFont {
+				font_size: crate::constants::UI_FONT_SIZE_LARGE,
+				..default()
+			},
+			TextColor(Color::srgb(0.9, 0.9, 0.3)),
+			Node {
+				margin: UiRect::bottom(Val::Px(crate::constants::POWERUP_TITLE_MARGIN)),
+				..default()
+			},
+		))
+		.id();
+
+	commands.entity(button_container).add_child(title);
+
+	// First button is selected by default
+	let button_color = |index: usize| {
+		if index == 0 {
+			crate::constants::POWERUP_COLOR_SELECTED
+		} else {
+			crate::constants::POWERUP_COLOR_NORMAL
+		}
+	};
+
+	// Create buttons for each option
+	for (index, powerup) in options.iter().enumerate() {
+		// Cards are outlined in their rarity color, evolutions in gold
+		let border_color = match powerup {
+			crate::PowerupDefinition::Evolution(_) => crate::constants::POWERUP_COLOR_EVOLUTION,
+			_ => config_data
+				.powerup_pool
+				.iter()
+				.find(|entry| entry.powerup == *powerup)
+				.map(|entry| entry.rarity)
+				.unwrap_or_default()
+				.color(),
+		};
+
+		let button = commands
 			.spawn((
+				Button,
 				Node {
-					width: Val::Percent(100.0),
-					height: Val::Percent(100.0),
-					position_type: PositionType::Absolute,
+					width: Val::Px(crate::constants::POWERUP_BUTTON_WIDTH),
+					height: Val::Px(crate::constants::POWERUP_BUTTON_HEIGHT),
 					justify_content: JustifyContent::Center,
 					align_items: AlignItems::Center,
+					padding: UiRect::all(Val::Px(crate::constants::POWERUP_BUTTON_PADDING)),
+					border: UiRect::all(Val::Px(crate::constants::POWERUP_CARD_BORDER)),
 					..default()
 				},
-				BackgroundColor(Color::srgba(
-					0.0,
-					0.0,
-					0.0,
-					crate::constants::POWERUP_OVERLAY_ALPHA,
-				)),
-				PowerupUIContainer,
+				BackgroundColor(button_color(index)),
+				BorderColor::all(border_color),
+				PowerupButton {
+					choice: PowerupChoice::Powerup(powerup.clone()),
+					index,
+				},
 			))
 			.id();
 
-		let button_container = commands
+		let name_color = match powerup {
+			crate::PowerupDefinition::Evolution(_) => crate::constants::POWERUP_COLOR_EVOLUTION,
+			_ => Color::WHITE,
+		};
+
+		let text_container = commands
 			.spawn(Node {
 				flex_direction: FlexDirection::Column,
-				row_gap: Val::Px(crate::constants::POWERUP_BUTTON_GAP),
 				..default()
 			})
 			.id();
 
-		commands.entity(container).add_child(button_container);
+		let name_text = commands
+			.spawn((
+				Text::new(get_powerup_name(
+					powerup,
+					weapon_resources,
+					weapon_inventory,
+				)),
+				TextFont {
+					font_size: crate::constants::UI_FONT_SIZE_MEDIUM,
+					..default()
+				},
+				TextColor(name_color),
+			))
+			.id();
 
-		// Title
-		let title = commands
+		let desc_text = commands
 			.spawn((
-				Text::new("LEVEL UP! Choose a Powerup:"),
+				Text::new(get_powerup_description(
+					powerup,
+					weapon_resources,
+					weapon_inventory,
+				)),
 				TextFont {
-					font_size: crate::constants::UI_FONT_SIZE_LARGE,
+					font_size: crate::constants::UI_FONT_SIZE_SMALL,
 					..default()
 				},
-				TextColor(Color::srgb(0.9, 0.9, 0.3)),
+				TextColor(Color::srgb(0.7, 0.7, 0.7)),
+			))
+			.id();
+
+		commands.entity(text_container).add_child(name_text);
+		commands.entity(text_container).add_child(desc_text);
+		commands.entity(button).add_child(text_container);
+		commands.entity(button_container).add_child(button);
+	}
+
+	// Reroll, skip and banish follow the options so they share the same navigation
+	let choices = action_choices(
+		options,
+		powerup_actions,
+		&config_data.skip_reward,
+		banishing,
+	);
+	for (offset, (choice, label)) in choices.into_iter().enumerate() {
+		let index = options.len() + offset;
+		let button = commands
+			.spawn((
+				Button,
 				Node {
-					margin: UiRect::bottom(Val::Px(crate::constants::POWERUP_TITLE_MARGIN)),
+					width: Val::Px(crate::constants::POWERUP_BUTTON_WIDTH),
+					height: Val::Px(crate::constants::POWERUP_ACTION_BUTTON_HEIGHT),
+					justify_content: JustifyContent::Center,
+					align_items: AlignItems::Center,
 					..default()
 				},
+				BackgroundColor(button_color(index)),
+				PowerupButton { choice, index },
 			))
 			.id();
 
-		commands.entity(button_container).add_child(title);
+		let label_text = commands
+			.spawn((
+				Text::new(label),
+				TextFont {
+					font_size: crate::constants::UI_FONT_SIZE_NORMAL,
+					..default()
+				},
+				TextColor(Color::srgb(0.8, 0.8, 0.8)),
+			))
+			.id();
 
-		// Create buttons for each option
-		for (index, powerup) in options.iter().enumerate() {
-			// First button is selected by default
-			let bg_color = if index == 0 {
-				crate::constants::POWERUP_COLOR_SELECTED
-			} else {
-				crate::constants::POWERUP_COLOR_NORMAL
-			};
+		commands.entity(button).add_child(label_text);
+		commands.entity(button_container).add_child(button);
+	}
+}
 
-			let button = commands
-				.spawn((
-					Button,
-					Node {
-						width: Val::Px(crate::constants::POWERUP_BUTTON_WIDTH),
-						height: Val::Px(crate::constants::POWERUP_BUTTON_HEIGHT),
-						justify_content: JustifyContent::Center,
-						align_items: AlignItems::Center,
-						padding: UiRect::all(Val::Px(crate::constants::POWERUP_BUTTON_PADDING)),
-						..default()
-					},
-					BackgroundColor(bg_color),
-					PowerupButton {
-						powerup_def: powerup.clone(),
-						index,
-					},
-				))
-				.id();
-
-			let text_container = commands
-				.spawn(Node {
-					flex_direction: FlexDirection::Column,
-					..default()
-				})
-				.id();
-
-			let name_text = commands
-				.spawn((
-					Text::new(get_powerup_name(
-						powerup,
-						&weapon_resources,
-						&weapon_inventory,
-					)),
-					TextFont {
-						font_size: crate::constants::UI_FONT_SIZE_MEDIUM,
-						..default()
-					},
-					TextColor(Color::WHITE),
-				))
-				.id();
-
-			let desc_text = commands
-				.spawn((
-					Text::new(get_powerup_description(
-						powerup,
-						&weapon_resources,
-						&weapon_inventory,
-					)),
-					TextFont {
-						font_size: crate::constants::UI_FONT_SIZE_SMALL,
-						..default()
-					},
-					TextColor(Color::srgb(0.7, 0.7, 0.7)),
-				))
-				.id();
-
-			commands.entity(text_container).add_child(name_text);
-			commands.entity(text_container).add_child(desc_text);
-			commands.entity(button).add_child(text_container);
-			commands.entity(button_container).add_child(button);
-		}
+#[allow(clippy::too_many_arguments)]
+pub fn handle_level_up(
+	mut commands: Commands,
+	mut level_up_events: MessageReader<crate::experience::LevelUpEvent>,
+	mut powerup_state: ResMut<super::PowerupState>,
+	mut time: ResMut<Time<Virtual>>,
+	game_config: Option<Res<crate::GameConfig>>,
+	config_assets: Res<Assets<crate::GameConfigData>>,
+	weapon_resources: super::WeaponResources,
+	players: Query<(
+		&crate::player::Player,
+		&crate::player::PlayerStats,
+		&crate::weapons::WeaponInventory,
+		&super::PowerupHistory,
+		&super::PowerupActions,
+		&crate::input::PlayerController,
+	)>,
+) {
+	// Each level gained opens its own menu, one after another
+	for event in level_up_events.read() {
+		powerup_state.pending.push_back(event.player);
+	}
+
+	if powerup_state.showing {
+		return;
+	}
+
+	let Some(game_config) = game_config.as_ref() else {
+		return;
+	};
+
+	let Some(config_data) = config_assets.get(&game_config.config_handle) else {
+		return;
+	};
+
+	// Players who left or died while waiting are skipped
+	while let Some(player_entity) = powerup_state.pending.pop_front() {
+		let Ok((player, stats, weapon_inventory, powerup_history, powerup_actions, controller)) =
+			players.get(player_entity)
+		else {
+			continue;
+		};
+
+		let options = roll_powerup_options(
+			config_data,
+			(player, stats),
+			weapon_inventory,
+			powerup_history,
+			powerup_actions,
+			&weapon_resources,
+		);
+
+		powerup_state.showing = true;
+		powerup_state.options = options.clone();
+		powerup_state.selected_index = 0;
+		powerup_state.player = Some(player_entity);
+		powerup_state.banishing = false;
+
+		// Pause the game
+		time.pause();
+
+		spawn_powerup_menu(
+			&mut commands,
+			menu_title(players.iter().count(), controller, false),
+			&options,
+			powerup_actions,
+			config_data,
+			false,
+			&weapon_resources,
+			weapon_inventory,
+		);
+
+		break;
 	}
 }
 
+type PowerupPlayerQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'static mut crate::player::Player,
+		&'static mut crate::behaviors::Damageable,
+		&'static mut crate::behaviors::PlayerEnergy,
+		&'static mut crate::player::PlayerStats,
+		&'static mut crate::weapons::WeaponInventory,
+		&'static mut super::PowerupHistory,
+		&'static mut super::PowerupActions,
+		&'static mut crate::experience::PlayerExperience,
+		&'static crate::input::PlayerController,
+	),
+	With<crate::behaviors::PlayerTag>,
+>;
+
 #[allow(clippy::too_many_arguments)]
 pub fn handle_powerup_selection(
 	mut commands: Commands,
@@ -257,34 +597,46 @@ pub fn handle_powerup_selection(
 	>,
 	button_query: Query<&PowerupButton>,
 	mut ui_state: PowerupUIState,
-	mut player_query: Query<
-		(
-			&mut crate::player::Player,
-			&mut crate::behaviors::Damageable,
-			&mut crate::behaviors::PlayerEnergy,
-		),
-		With<crate::behaviors::PlayerTag>,
-	>,
-	input: InputState,
+	mut player_query: PowerupPlayerQuery,
+	input: crate::input::ActionInput,
 	weapon_resources: super::WeaponResources,
-	mut weapon_inventory: ResMut<crate::weapons::WeaponInventory>,
 	mut weapon_level_query: Query<&mut crate::behaviors::WeaponLevel>,
+	game_config: Option<Res<crate::GameConfig>>,
+	config_assets: Res<Assets<crate::GameConfigData>>,
 ) {
+	if !ui_state.state.showing {
+		return;
+	}
+
+	let player_count = player_query.iter().count();
+
+	// The menu closes if the player choosing from it is gone
+	let Some(Ok((
+		player_entity,
+		mut player,
+		mut damageable,
+		mut player_energy,
+		mut stats,
+		mut weapon_inventory,
+		mut powerup_history,
+		mut powerup_actions,
+		mut player_xp,
+		controller,
+	))) = ui_state
+		.state
+		.player
+		.map(|entity| player_query.get_mut(entity))
+	else {
+		cleanup_powerup_ui(&mut commands, &mut ui_state);
+		return;
+	};
+
 	// Handle mouse interactions
+	let mut chosen = None;
 	for (button, interaction, mut bg_color) in interaction_query.iter_mut() {
 		match *interaction {
 			Interaction::Pressed => {
-				if let Ok((mut player, mut damageable, mut player_energy)) = player_query.single_mut() {
-					super::application::apply_powerup(
-						&button.powerup_def,
-						&mut commands,
-						(&mut player, &mut damageable, &mut player_energy),
-						&weapon_resources,
-						&mut weapon_inventory,
-						&mut weapon_level_query,
-					);
-				}
-				cleanup_powerup_ui(&mut commands, &mut ui_state);
+				chosen = Some(button.choice.clone());
 			}
 			Interaction::Hovered => {
 				*bg_color = crate::constants::POWERUP_COLOR_HOVERED.into();
@@ -301,86 +653,152 @@ pub fn handle_powerup_selection(
 		}
 	}
 
-	if !ui_state.state.showing {
-		return;
+	if chosen.is_none() && input.just_pressed(controller, crate::input::InputAction::MenuConfirm) {
+		chosen = button_query
+			.iter()
+			.find(|button| button.index == ui_state.state.selected_index)
+			.map(|button| button.choice.clone());
 	}
 
-	// Check for confirmation input (gamepad or keyboard)
-	let mut should_confirm = false;
+	let Some(choice) = chosen else {
+		return;
+	};
 
-	// Gamepad confirmation
-	for gamepad in input.gamepads.iter() {
-		if gamepad.just_pressed(GamepadButton::South) {
-			should_confirm = true;
-			break;
+	let Some(config_data) = game_config
+		.as_ref()
+		.and_then(|config| config_assets.get(&config.config_handle))
+	else {
+		return;
+	};
+
+	let mut banish_refused = false;
+	match choice {
+		// Evolutions never come from the pool, so there's nothing to banish
+		PowerupChoice::Powerup(crate::PowerupDefinition::Evolution(_))
+			if ui_state.state.banishing =>
+		{
+			ui_state.state.banishing = false;
+			banish_refused = true;
 		}
-	}
-
-	// Keyboard confirmation
-	if input.keyboard.just_pressed(KeyCode::Enter) || input.keyboard.just_pressed(KeyCode::Space) {
-		should_confirm = true;
-	}
-
-	if should_confirm {
-		for button in button_query.iter() {
-			if button.index == ui_state.state.selected_index {
-				if let Ok((mut player, mut damageable, mut player_energy)) = player_query.single_mut() {
-					super::application::apply_powerup(
-						&button.powerup_def,
-						&mut commands,
-						(&mut player, &mut damageable, &mut player_energy),
-						&weapon_resources,
-						&mut weapon_inventory,
-						&mut weapon_level_query,
-					);
+		PowerupChoice::Powerup(powerup) if ui_state.state.banishing => {
+			powerup_actions.banishes = powerup_actions.banishes.saturating_sub(1);
+			powerup_actions.banished.push(powerup.clone());
+			ui_state.state.banishing = false;
+			replace_banished_option(
+				&mut ui_state.state.options,
+				&powerup,
+				&stats,
+				config_data,
+				(&weapon_inventory, &weapon_resources),
+				&powerup_history,
+				&powerup_actions,
+			);
+		}
+		PowerupChoice::Powerup(powerup) => {
+			super::application::apply_powerup(
+				&powerup,
+				&mut commands,
+				(
+					player_entity,
+					&mut player,
+					&mut damageable,
+					&mut player_energy,
+					&mut stats,
+				),
+				&weapon_resources,
+				&mut weapon_inventory,
+				&mut weapon_level_query,
+				&mut powerup_history,
+			);
+			cleanup_powerup_ui(&mut commands, &mut ui_state);
+			return;
+		}
+		PowerupChoice::Reroll => {
+			powerup_actions.rerolls = powerup_actions.rerolls.saturating_sub(1);
+			ui_state.state.options = roll_powerup_options(
+				config_data,
+				(&player, &stats),
+				&weapon_inventory,
+				&powerup_history,
+				&powerup_actions,
+				&weapon_resources,
+			);
+		}
+		PowerupChoice::Skip => {
+			if !ui_state.state.options.is_empty() {
+				powerup_actions.skips = powerup_actions.skips.saturating_sub(1);
+			}
+			match config_data.skip_reward {
+				super::SkipReward::Heal(amount) => {
+					damageable.health = (damageable.health + amount).min(damageable.max_health);
+				}
+				super::SkipReward::Xp(amount) => {
+					player_xp.current_xp += amount;
 				}
-				cleanup_powerup_ui(&mut commands, &mut ui_state);
-				break;
 			}
+			cleanup_powerup_ui(&mut commands, &mut ui_state);
+			return;
 		}
+		PowerupChoice::Banish => {
+			ui_state.state.banishing = !ui_state.state.banishing;
+		}
+	}
+
+	// Rerolls and banish mode changes rebuild the menu in place, keeping the game paused
+	for entity in ui_state.ui_query.iter() {
+		commands.entity(entity).despawn();
 	}
+	ui_state.state.selected_index = 0;
+	let mut title = menu_title(player_count, controller, ui_state.state.banishing);
+	if banish_refused {
+		title.push_str("\n(Evolutions can't be banished)");
+	}
+	spawn_powerup_menu(
+		&mut commands,
+		title,
+		&ui_state.state.options,
+		&powerup_actions,
+		config_data,
+		ui_state.state.banishing,
+		&weapon_resources,
+		&weapon_inventory,
+	);
 }
 
 pub fn handle_powerup_navigation(
 	mut ui_state: PowerupUIState,
-	input: InputState,
+	input: crate::input::ActionInput,
 	mut button_query: Query<(&PowerupButton, &mut BackgroundColor)>,
+	controllers: Query<&crate::input::PlayerController>,
 ) {
-	if !ui_state.state.showing || ui_state.state.options.is_empty() {
+	// Options plus the reroll, skip and banish buttons
+	let num_buttons = button_query.iter().count();
+	if !ui_state.state.showing || num_buttons == 0 {
 		return;
 	}
 
-	let mut direction = 0i32;
+	// Only the player choosing can move the selection
+	let Some(Ok(controller)) = ui_state.state.player.map(|entity| controllers.get(entity)) else {
+		return;
+	};
 
-	// Keyboard navigation
-	if input.keyboard.just_pressed(KeyCode::ArrowUp) || input.keyboard.just_pressed(KeyCode::KeyW) {
+	let mut direction = 0i32;
+	if input.just_pressed(controller, crate::input::InputAction::MenuUp) {
 		direction = -1;
 	}
-	if input.keyboard.just_pressed(KeyCode::ArrowDown) || input.keyboard.just_pressed(KeyCode::KeyS)
-	{
+	if input.just_pressed(controller, crate::input::InputAction::MenuDown) {
 		direction = 1;
 	}
 
-	// Gamepad navigation
-	for gamepad in input.gamepads.iter() {
-		if gamepad.just_pressed(GamepadButton::DPadUp) {
-			direction = -1;
-		}
-		if gamepad.just_pressed(GamepadButton::DPadDown) {
-			direction = 1;
-		}
-	}
-
 	if direction != 0 {
-		let num_options = ui_state.state.options.len();
 		if direction < 0 {
 			ui_state.state.selected_index = if ui_state.state.selected_index == 0 {
-				num_options - 1
+				num_buttons - 1
 			} else {
 				ui_state.state.selected_index - 1
 			};
 		} else {
-			ui_state.state.selected_index = (ui_state.state.selected_index + 1) % num_options;
+			ui_state.state.selected_index = (ui_state.state.selected_index + 1) % num_buttons;
 		}
 
 		// Update button colors based on selection
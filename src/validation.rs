@@ -15,13 +15,17 @@ struct ValidationState {
 	validated: bool,
 }
 
+#[allow(clippy::too_many_arguments)]
 fn validate_game_config(
 	game_config: Option<Res<crate::GameConfig>>,
 	config_assets: Res<Assets<crate::GameConfigData>>,
 	weapon_registry: Option<Res<crate::weapons::WeaponRegistry>>,
 	enemy_registry: Option<Res<crate::enemy::EnemyRegistry>>,
+	character_registry: Option<Res<crate::character::CharacterRegistry>>,
 	weapon_assets: Res<Assets<crate::weapons::WeaponData>>,
 	enemy_assets: Res<Assets<crate::enemy::EnemyData>>,
+	character_assets: Res<Assets<crate::character::CharacterData>>,
+	asset_server: Res<AssetServer>,
 	mut validation_state: ResMut<ValidationState>,
 ) {
 	if validation_state.validated {
@@ -29,9 +33,12 @@ fn validate_game_config(
 	}
 
 	// Wait for registries to be initialized
-	let (Some(config), Some(_weapon_registry), Some(_enemy_registry)) =
-		(game_config, weapon_registry, enemy_registry)
-	else {
+	let (Some(config), Some(weapon_registry), Some(enemy_registry), Some(character_registry)) = (
+		game_config,
+		weapon_registry,
+		enemy_registry,
+		character_registry,
+	) else {
 		return;
 	};
 
@@ -39,6 +46,30 @@ fn validate_game_config(
 		return;
 	};
 
+	// Per-asset checks need the data, so wait until every file has loaded or failed
+	let handles = config_data
+		.weapon_ids
+		.iter()
+		.filter_map(|id| weapon_registry.get(id))
+		.map(|handle| handle.id().untyped())
+		.chain(
+			config_data
+				.enemy_ids
+				.iter()
+				.filter_map(|id| enemy_registry.get(id))
+				.map(|handle| handle.id().untyped()),
+		)
+		.chain(
+			config_data
+				.character_ids
+				.iter()
+				.filter_map(|id| character_registry.get(id))
+				.map(|handle| handle.id().untyped()),
+		);
+	if !assets_settled(&asset_server, handles) {
+		return;
+	}
+
 	let mut errors = Vec::new();
 
 	// Validate weapon IDs
@@ -47,14 +78,46 @@ fn validate_game_config(
 	// Validate enemy IDs
 	validate_ids(&config_data.enemy_ids, "enemy", &mut errors);
 
-	// Validate initial weapons reference valid weapon IDs
-	validate_initial_weapons(config_data, &mut errors);
+	// Validate character IDs
+	validate_ids(&config_data.character_ids, "character", &mut errors);
+
+	// Validate character stats, starting weapons and passives
+	validate_characters(
+		config_data,
+		&character_registry,
+		&character_assets,
+		&mut errors,
+	);
 
 	// Validate powerup pool references
 	validate_powerup_pool(config_data, &mut errors);
 
+	// Validate evolution recipes
+	validate_evolutions(config_data, &mut errors);
+
+	// Validate weapon level tables
+	validate_weapon_levels(config_data, &weapon_registry, &weapon_assets, &mut errors);
+
+	// Validate weapon slot loadout and weapon slot references
+	validate_weapon_slots(config_data, &weapon_registry, &weapon_assets, &mut errors);
+
+	// Validate pickup settings and enemy drop tables
+	validate_pickups(config_data, &enemy_registry, &enemy_assets, &mut errors);
+
+	// Validate XP curve and level rewards
+	validate_xp_curve(config_data, &mut errors);
+
+	// Validate the reward for skipping a level-up menu
+	validate_skip_reward(config_data, &mut errors);
+
 	// Validate asset loading status
-	validate_asset_loading(config_data, &weapon_assets, &enemy_assets, &mut errors);
+	validate_asset_loading(
+		config_data,
+		&weapon_assets,
+		&enemy_assets,
+		&character_assets,
+		&mut errors,
+	);
 
 	if !errors.is_empty() {
 		error!("Asset validation failed with {} error(s):", errors.len());
@@ -68,6 +131,21 @@ fn validate_game_config(
 	validation_state.validated = true;
 }
 
+// True once none of the assets is still loading; failed ones count as settled
+fn assets_settled(
+	asset_server: &AssetServer,
+	mut handles: impl Iterator<Item = bevy::asset::UntypedAssetId>,
+) -> bool {
+	use bevy::asset::LoadState;
+
+	handles.all(|id| {
+		!matches!(
+			asset_server.load_state(id),
+			LoadState::NotLoaded | LoadState::Loading
+		)
+	})
+}
+
 fn validate_ids(ids: &[String], id_type: &str, errors: &mut Vec<String>) {
 	let mut seen = HashSet::new();
 
@@ -99,26 +177,86 @@ fn validate_ids(ids: &[String], id_type: &str, errors: &mut Vec<String>) {
 	}
 }
 
-fn validate_initial_weapons(
+fn validate_characters(
 	config_data: &crate::GameConfigData,
+	character_registry: &crate::character::CharacterRegistry,
+	character_assets: &Assets<crate::character::CharacterData>,
 	errors: &mut Vec<String>,
 ) {
+	if config_data.character_ids.is_empty() {
+		errors.push("No characters listed in character_ids in game_config.ron".to_string());
+	}
+
 	let valid_weapon_ids: HashSet<_> = config_data.weapon_ids.iter().collect();
 
-	for initial_weapon in &config_data.initial_weapons {
-		if !valid_weapon_ids.contains(&initial_weapon.weapon_id) {
+	for character_id in &config_data.character_ids {
+		let Some(character) = character_registry
+			.get(character_id)
+			.and_then(|handle| character_assets.get(handle))
+		else {
+			errors.push(format!(
+				"Character '{}' failed to load from characters/{}.character.ron",
+				character_id, character_id
+			));
+			continue;
+		};
+
+		if character.name.is_empty() {
+			errors.push(format!("Character '{}' has an empty name", character_id));
+		}
+
+		for (stat, value) in [
+			("speed", character.speed),
+			("jump_force", character.jump_force),
+			("max_health", character.max_health),
+			("max_energy", character.max_energy),
+		] {
+			if value <= 0.0 {
+				errors.push(format!(
+					"Character '{}' has non-positive {} {}",
+					character_id, stat, value
+				));
+			}
+		}
+
+		if character.energy_regen < 0.0 {
 			errors.push(format!(
-				"Initial weapon references unknown weapon ID '{}'",
-				initial_weapon.weapon_id
+				"Character '{}' has negative energy_regen {}",
+				character_id, character.energy_regen
 			));
 		}
 
-		if initial_weapon.level == 0 {
+		if character.starting_weapons.is_empty() {
 			errors.push(format!(
-				"Initial weapon '{}' has invalid level 0 (levels start at 1)",
-				initial_weapon.weapon_id
+				"Character '{}' has no starting weapons",
+				character_id
 			));
 		}
+
+		for starting_weapon in &character.starting_weapons {
+			if !valid_weapon_ids.contains(&starting_weapon.weapon_id) {
+				errors.push(format!(
+					"Character '{}' starting weapon references unknown weapon ID '{}'",
+					character_id, starting_weapon.weapon_id
+				));
+			}
+
+			if starting_weapon.level == 0 {
+				errors.push(format!(
+					"Character '{}' starting weapon '{}' has invalid level 0 (levels start at 1)",
+					character_id, starting_weapon.weapon_id
+				));
+			}
+		}
+
+		if let Some(passive) = &character.passive {
+			if passive.every_levels == 0 {
+				errors.push(format!(
+					"Character '{}' passive '{}' has invalid every_levels 0",
+					character_id, passive.name
+				));
+			}
+		}
 	}
 }
 
@@ -127,16 +265,400 @@ fn validate_powerup_pool(
 	errors: &mut Vec<String>,
 ) {
 	let valid_weapon_ids: HashSet<_> = config_data.weapon_ids.iter().collect();
+	let mut stat_boost_names = HashSet::new();
+
+	for entry in &config_data.powerup_pool {
+		match &entry.powerup {
+			crate::PowerupDefinition::Weapon(weapon_id) => {
+				if !valid_weapon_ids.contains(weapon_id) {
+					errors.push(format!(
+						"Powerup pool references unknown weapon ID '{}'",
+						weapon_id
+					));
+				}
+			}
+			crate::PowerupDefinition::StatBoost(boost) => {
+				// Names identify stat boosts in history, requires and excludes
+				if !stat_boost_names.insert(boost.name.as_str()) {
+					errors.push(format!(
+						"Powerup pool has more than one stat boost named '{}'",
+						boost.name
+					));
+				}
+			}
+			crate::PowerupDefinition::Evolution(_) => {}
+		}
+	}
+
+	for entry in &config_data.powerup_pool {
+		let id = entry.powerup.id();
+
+		if !entry.weight.is_finite() || entry.weight <= 0.0 {
+			errors.push(format!(
+				"Powerup '{}' has non-positive weight {}",
+				id, entry.weight
+			));
+		}
+
+		if entry.max_stacks == Some(0) {
+			errors.push(format!("Powerup '{}' has max_stacks of 0", id));
+		}
+
+		let known = |other: &str| {
+			valid_weapon_ids.iter().any(|weapon_id| *weapon_id == other)
+				|| stat_boost_names.contains(other)
+		};
+
+		for requirement in &entry.requires {
+			match requirement {
+				crate::PowerupRequirement::Powerup(other) => {
+					if !known(other) {
+						errors.push(format!(
+							"Powerup '{}' requires unknown powerup '{}'",
+							id, other
+						));
+					} else if other == id {
+						errors.push(format!("Powerup '{}' requires itself", id));
+					}
+				}
+				crate::PowerupRequirement::WeaponLevel(weapon_id, _level) => {
+					if !valid_weapon_ids.contains(weapon_id) {
+						errors.push(format!(
+							"Powerup '{}' requires unknown weapon ID '{}'",
+							id, weapon_id
+						));
+					}
+				}
+			}
+		}
+
+		for other in &entry.excludes {
+			if !known(other) {
+				errors.push(format!(
+					"Powerup '{}' excludes unknown powerup '{}'",
+					id, other
+				));
+			}
+		}
+	}
+}
+
+fn validate_evolutions(
+	config_data: &crate::GameConfigData,
+	errors: &mut Vec<String>,
+) {
+	let valid_weapon_ids: HashSet<_> = config_data.weapon_ids.iter().collect();
+	let stat_boost_names: HashSet<_> = config_data
+		.powerup_pool
+		.iter()
+		.filter_map(|entry| match &entry.powerup {
+			crate::PowerupDefinition::StatBoost(boost) => Some(&boost.name),
+			_ => None,
+		})
+		.collect();
 
-	for powerup in &config_data.powerup_pool {
-		if let crate::PowerupDefinition::Weapon(weapon_id) = powerup {
+	for recipe in &config_data.evolutions {
+		for weapon_id in [&recipe.base_weapon, &recipe.evolved_weapon] {
 			if !valid_weapon_ids.contains(weapon_id) {
 				errors.push(format!(
-					"Powerup pool references unknown weapon ID '{}'",
+					"Evolution recipe references unknown weapon ID '{}'",
 					weapon_id
 				));
 			}
 		}
+
+		if recipe.base_weapon == recipe.evolved_weapon {
+			errors.push(format!(
+				"Evolution recipe for '{}' evolves into itself",
+				recipe.base_weapon
+			));
+		}
+
+		if recipe.base_level == 0 {
+			errors.push(format!(
+				"Evolution recipe for '{}' has invalid base level 0 (levels start at 1)",
+				recipe.base_weapon
+			));
+		}
+
+		match &recipe.requires {
+			crate::EvolutionRequirement::StatBoost(name) => {
+				if !stat_boost_names.contains(name) {
+					errors.push(format!(
+						"Evolution recipe for '{}' requires unknown stat boost '{}'",
+						recipe.base_weapon, name
+					));
+				}
+			}
+			crate::EvolutionRequirement::Weapon(weapon_id) => {
+				if !valid_weapon_ids.contains(weapon_id) {
+					errors.push(format!(
+						"Evolution recipe for '{}' requires unknown weapon ID '{}'",
+						recipe.base_weapon, weapon_id
+					));
+				}
+			}
+		}
+	}
+}
+
+fn validate_weapon_levels(
+	config_data: &crate::GameConfigData,
+	weapon_registry: &crate::weapons::WeaponRegistry,
+	weapon_assets: &Assets<crate::weapons::WeaponData>,
+	errors: &mut Vec<String>,
+) {
+	for weapon_id in &config_data.weapon_ids {
+		let Some(weapon_data) = weapon_registry
+			.get(weapon_id)
+			.and_then(|handle| weapon_assets.get(handle))
+		else {
+			errors.push(format!(
+				"Weapon '{}' failed to load from weapons/{}.weapon.ron",
+				weapon_id, weapon_id
+			));
+			continue;
+		};
+
+		if !weapon_data.levels.is_empty() && !weapon_data.upgrade_behaviors.is_empty() {
+			errors.push(format!(
+				"Weapon '{}' defines both levels and upgrade_behaviors (use one)",
+				weapon_id
+			));
+		}
+
+		if let Some(max_level) = weapon_data.max_level {
+			if max_level == 0 {
+				errors.push(format!(
+					"Weapon '{}' has invalid max_level 0 (levels start at 1)",
+					weapon_id
+				));
+			} else if weapon_data.levels.len() as u32 >= max_level {
+				errors.push(format!(
+					"Weapon '{}' has {} level entries but max_level {} only allows {}",
+					weapon_id,
+					weapon_data.levels.len(),
+					max_level,
+					max_level - 1
+				));
+			} else if !weapon_data.levels.is_empty()
+				&& max_level > weapon_data.levels.len() as u32 + 1
+			{
+				// Levels past the table would change nothing
+				errors.push(format!(
+					"Weapon '{}' has max_level {} but only {} level entries (needs {})",
+					weapon_id,
+					max_level,
+					weapon_data.levels.len(),
+					max_level - 1
+				));
+			}
+		}
+
+		for (index, level) in weapon_data.levels.iter().enumerate() {
+			if level.deltas.is_empty() {
+				errors.push(format!(
+					"Weapon '{}' level {} has no stat deltas",
+					weapon_id,
+					index + 2
+				));
+			}
+		}
+	}
+}
+
+fn validate_weapon_slots(
+	config_data: &crate::GameConfigData,
+	weapon_registry: &crate::weapons::WeaponRegistry,
+	weapon_assets: &Assets<crate::weapons::WeaponData>,
+	errors: &mut Vec<String>,
+) {
+	use crate::SlotActivation;
+
+	let mut seen = HashSet::new();
+
+	for slot in &config_data.weapon_slots {
+		if slot.name.is_empty() {
+			errors.push("Empty weapon slot name found in game_config.ron".to_string());
+		} else if !seen.insert(slot.name.as_str()) {
+			errors.push(format!("Duplicate weapon slot '{}'", slot.name));
+		}
+
+		if let Some(key) = &slot.key {
+			if crate::input::parse_key_code(key).is_none() {
+				errors.push(format!(
+					"Weapon slot '{}' has unknown key '{}'",
+					slot.name, key
+				));
+			}
+		}
+
+		if let Some(button) = &slot.gamepad_button {
+			if crate::input::parse_gamepad_button(button).is_none() {
+				errors.push(format!(
+					"Weapon slot '{}' has unknown gamepad button '{}'",
+					slot.name, button
+				));
+			}
+		}
+
+		if slot.activation != SlotActivation::AlwaysOn
+			&& slot.key.is_none()
+			&& slot.gamepad_button.is_none()
+		{
+			errors.push(format!(
+				"Weapon slot '{}' needs a key or gamepad_button for {:?} activation",
+				slot.name, slot.activation
+			));
+		}
+	}
+
+	for weapon_id in &config_data.weapon_ids {
+		// Load failures are reported by validate_weapon_levels
+		let Some(weapon_data) = weapon_registry
+			.get(weapon_id)
+			.and_then(|handle| weapon_assets.get(handle))
+		else {
+			continue;
+		};
+
+		if let Some(slot_name) = weapon_data.slot_name() {
+			if !seen.contains(slot_name.as_str()) {
+				errors.push(format!(
+					"Weapon '{}' uses slot '{}' which is not in weapon_slots",
+					weapon_id, slot_name
+				));
+			}
+		}
+	}
+}
+
+fn validate_pickups(
+	config_data: &crate::GameConfigData,
+	enemy_registry: &crate::enemy::EnemyRegistry,
+	enemy_assets: &Assets<crate::enemy::EnemyData>,
+	errors: &mut Vec<String>,
+) {
+	use crate::pickups::PickupType;
+
+	for pickup_type in PickupType::ALL {
+		let Some(settings) = config_data.pickups.get(&pickup_type) else {
+			errors.push(format!(
+				"Pickup type {:?} has no entry in pickups",
+				pickup_type
+			));
+			continue;
+		};
+
+		if settings.size.0 <= 0.0 || settings.size.1 <= 0.0 {
+			errors.push(format!(
+				"Pickup type {:?} has non-positive size {:?}",
+				pickup_type, settings.size
+			));
+		}
+
+		if settings.collection_range <= 0.0 {
+			errors.push(format!(
+				"Pickup type {:?} has non-positive collection_range {}",
+				pickup_type, settings.collection_range
+			));
+		}
+
+		if settings.attraction_range < 0.0 || settings.movement_speed < 0.0 {
+			errors.push(format!(
+				"Pickup type {:?} has negative attraction_range or movement_speed",
+				pickup_type
+			));
+		}
+	}
+
+	for enemy_id in &config_data.enemy_ids {
+		let Some(enemy_data) = enemy_registry
+			.get(enemy_id)
+			.and_then(|handle| enemy_assets.get(handle))
+		else {
+			errors.push(format!(
+				"Enemy '{}' failed to load from enemies/{}.enemy.ron",
+				enemy_id, enemy_id
+			));
+			continue;
+		};
+
+		for drop in &enemy_data.drops {
+			if drop.chance <= 0.0 || drop.chance > 1.0 {
+				errors.push(format!(
+					"Enemy '{}' drops {:?} with chance {} outside (0, 1]",
+					enemy_id, drop.pickup, drop.chance
+				));
+			}
+
+			let needs_amount = matches!(drop.pickup, PickupType::Health | PickupType::Energy);
+			if needs_amount && drop.amount <= 0.0 {
+				errors.push(format!(
+					"Enemy '{}' drops {:?} without a positive amount",
+					enemy_id, drop.pickup
+				));
+			}
+		}
+	}
+}
+
+fn validate_xp_curve(config_data: &crate::GameConfigData, errors: &mut Vec<String>) {
+	use crate::experience::{LevelRewardType, XpCurve};
+
+	match &config_data.xp_curve {
+		XpCurve::Scaling { base, multiplier } => {
+			if *base == 0 {
+				errors.push("XP curve base must be greater than 0".to_string());
+			}
+			if *multiplier < 1.0 {
+				errors.push(format!(
+					"XP curve multiplier {} is below 1.0, levels would get cheaper",
+					multiplier
+				));
+			}
+		}
+		XpCurve::Table(table) => {
+			if table.is_empty() {
+				errors.push("XP curve table is empty".to_string());
+			}
+			if table.contains(&0) {
+				errors.push("XP curve table contains a level needing 0 XP".to_string());
+			}
+		}
+	}
+
+	for reward in &config_data.level_rewards {
+		// Players start at level 1, so that level is never reached
+		if reward.level < 2 {
+			errors.push(format!(
+				"Level reward at level {} can never be reached",
+				reward.level
+			));
+		}
+		let empty = match reward.reward {
+			LevelRewardType::ExtraOptions(count) => count == 0,
+			LevelRewardType::Rerolls(count)
+			| LevelRewardType::Skips(count)
+			| LevelRewardType::Banishes(count) => count == 0,
+			LevelRewardType::Stat(..) => false,
+		};
+		if empty {
+			errors.push(format!(
+				"Level reward at level {} grants a count of 0",
+				reward.level
+			));
+		}
+	}
+}
+
+fn validate_skip_reward(config_data: &crate::GameConfigData, errors: &mut Vec<String>) {
+	let positive = match config_data.skip_reward {
+		crate::powerups::SkipReward::Heal(amount) => amount > 0.0,
+		crate::powerups::SkipReward::Xp(amount) => amount > 0,
+	};
+	if !positive {
+		errors.push("Skip reward must grant a positive amount".to_string());
 	}
 }
 
@@ -144,6 +666,7 @@ fn validate_asset_loading(
 	config_data: &crate::GameConfigData,
 	weapon_assets: &Assets<crate::weapons::WeaponData>,
 	enemy_assets: &Assets<crate::enemy::EnemyData>,
+	character_assets: &Assets<crate::character::CharacterData>,
 	errors: &mut Vec<String>,
 ) {
 	// Check if all weapon assets are loaded
@@ -176,9 +699,24 @@ fn validate_asset_loading(
 		}
 	}
 
+	// Check if all character assets are loaded
+	for character_id in &config_data.character_ids {
+		if character_id.is_empty() {
+			continue; // Already reported
+		}
+
+		if character_id.contains("..") || character_id.contains('/') {
+			errors.push(format!(
+				"Character ID '{}' contains invalid path characters",
+				character_id
+			));
+		}
+	}
+
 	// Log warnings for assets that haven't loaded yet
 	let weapon_count = weapon_assets.len();
 	let enemy_count = enemy_assets.len();
+	let character_count = character_assets.len();
 
 	if weapon_count < config_data.weapon_ids.len() {
 		warn!(
@@ -195,4 +733,12 @@ fn validate_asset_loading(
 			config_data.enemy_ids.len()
 		);
 	}
+
+	if character_count < config_data.character_ids.len() {
+		warn!(
+			"Only {}/{} character assets have loaded",
+			character_count,
+			config_data.character_ids.len()
+		);
+	}
 }
@@ -2,13 +2,16 @@ use bevy::prelude::*;
 use std::f32::consts::PI;
 
 #[derive(Resource, Default)]
-pub struct OrbitingEntityCount(pub usize);
+pub struct OrbitingEntityCount(pub std::collections::HashMap<Entity, usize>); // Per owning player
 
 // Type aliases to reduce query complexity
 type FollowingEntityQuery<'w, 's> = Query<
 	'w,
 	's,
-	&'static mut Transform,
+	(
+		&'static mut Transform,
+		&'static crate::behaviors::WeaponOwner,
+	),
 	(
 		With<crate::behaviors::FollowPlayer>,
 		Without<crate::behaviors::OrbitingBehavior>,
@@ -20,15 +23,105 @@ type ProjectileSpawnerQuery<'w, 's> = Query<
 	'w,
 	's,
 	(
+		Entity,
 		&'static Transform,
 		&'static mut crate::behaviors::ProjectileSpawner,
 		Has<crate::behaviors::PlayerTag>,
 		Has<crate::behaviors::EnemyTag>,
+		Has<crate::behaviors::Deployed>,
 		Option<&'static crate::behaviors::WeaponSlot>,
+		Option<&'static crate::behaviors::WeaponOwner>,
+	),
+>;
+
+type BeamEmitterQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'static Transform,
+		&'static mut crate::behaviors::BeamEmitter,
+		Option<&'static crate::behaviors::WeaponSlot>,
+		Option<&'static crate::behaviors::WeaponOwner>,
+	),
+>;
+
+type BeamVisualQuery<'w, 's> = Query<
+	'w,
+	's,
+	(&'static mut Transform, &'static mut Sprite),
+	(
+		With<crate::behaviors::BeamVisual>,
+		Without<crate::behaviors::BeamEmitter>,
 	),
 >;
 
-// System to update non-orbiting weapons that follow the player
+type BeamTargetQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'static Transform,
+		&'static Sprite,
+		&'static mut crate::behaviors::Damageable,
+	),
+	(
+		With<crate::behaviors::EnemyTag>,
+		Without<crate::behaviors::BeamVisual>,
+	),
+>;
+
+type BeamGroundQuery<'w, 's> = Query<
+	'w,
+	's,
+	(&'static Transform, &'static Sprite),
+	(
+		With<crate::physics::Ground>,
+		Without<crate::behaviors::BeamVisual>,
+	),
+>;
+
+type BoomerangQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'static mut Transform,
+		&'static mut crate::physics::Velocity,
+		&'static Sprite,
+		&'static mut crate::behaviors::BoomerangProjectile,
+		Option<&'static crate::behaviors::WeaponOwner>,
+	),
+>;
+
+type BoomerangOwnerQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		&'static Transform,
+		&'static mut crate::behaviors::ProjectileSpawner,
+	),
+	Without<crate::behaviors::BoomerangProjectile>,
+>;
+
+type BoomerangTargetQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'static Transform,
+		&'static Sprite,
+		&'static mut crate::behaviors::Damageable,
+		Has<crate::behaviors::EnemyTag>,
+		Has<crate::behaviors::PlayerTag>,
+	),
+	(
+		Without<crate::behaviors::BoomerangProjectile>,
+		Without<crate::behaviors::Invulnerable>,
+	),
+>;
+
+// System to update non-orbiting weapons that follow their owning player
 pub fn update_following_entities(
 	mut following_query: FollowingEntityQuery,
 	player_query: Query<
@@ -39,8 +132,8 @@ pub fn update_following_entities(
 		),
 	>,
 ) {
-	if let Ok(player_transform) = player_query.single() {
-		for mut transform in following_query.iter_mut() {
+	for (mut transform, owner) in following_query.iter_mut() {
+		if let Ok(player_transform) = player_query.get(owner.0) {
 			// Simply match the player's position
 			transform.translation.x = player_transform.translation.x;
 			transform.translation.y = player_transform.translation.y;
@@ -53,7 +146,7 @@ pub fn update_orbiting_entities(
 	mut orbiting_query: Query<(
 		&mut Transform,
 		&mut crate::behaviors::OrbitingBehavior,
-		&crate::behaviors::FollowPlayer,
+		&crate::behaviors::WeaponOwner,
 	)>,
 	player_query: Query<
 		&Transform,
@@ -64,43 +157,56 @@ pub fn update_orbiting_entities(
 	>,
 	time: Res<Time<Virtual>>,
 ) {
-	if let Ok(player_transform) = player_query.single() {
-		for (mut transform, mut behavior, _) in orbiting_query.iter_mut() {
-			// Rotate based on speed
-			behavior.angle += behavior.speed * time.delta_secs();
-
-			// Update position relative to player
-			transform.translation.x =
-				player_transform.translation.x + behavior.angle.cos() * behavior.radius;
-			transform.translation.y =
-				player_transform.translation.y + behavior.angle.sin() * behavior.radius;
-			transform.rotation = Quat::from_rotation_z(behavior.angle + PI / 2.0);
-		}
+	for (mut transform, mut behavior, owner) in orbiting_query.iter_mut() {
+		let Ok(player_transform) = player_query.get(owner.0) else {
+			continue;
+		};
+
+		// Rotate based on speed
+		behavior.angle += behavior.speed * time.delta_secs();
+
+		// Update position relative to the owning player
+		transform.translation.x =
+			player_transform.translation.x + behavior.angle.cos() * behavior.radius;
+		transform.translation.y =
+			player_transform.translation.y + behavior.angle.sin() * behavior.radius;
+		transform.rotation = Quat::from_rotation_z(behavior.angle + PI / 2.0);
 	}
 }
 
-// System to redistribute orbiting entities when new ones are added
+// System to redistribute each player's orbiting entities when new ones are added
 pub fn redistribute_orbiting_entities(
 	mut all_orbiting: Query<
-		&mut crate::behaviors::OrbitingBehavior,
+		(
+			&mut crate::behaviors::OrbitingBehavior,
+			&crate::behaviors::WeaponOwner,
+		),
 		With<crate::behaviors::FollowPlayer>,
 	>,
 	mut count_tracker: ResMut<OrbitingEntityCount>,
 ) {
-	let current_count = all_orbiting.iter().count();
-
-	// Only redistribute if count changed (new entities added or removed)
-	if current_count != count_tracker.0 {
-		count_tracker.0 = current_count;
+	let mut current_counts = std::collections::HashMap::new();
+	for (_, owner) in all_orbiting.iter() {
+		*current_counts.entry(owner.0).or_insert(0) += 1;
+	}
 
-		// Redistribute all entities evenly
-		for (index, mut behavior) in all_orbiting.iter_mut().enumerate() {
-			behavior.angle = (index as f32 / current_count as f32) * 2.0 * PI;
+	// Only redistribute if a count changed (new entities added or removed)
+	if current_counts != count_tracker.0 {
+		// Redistribute each player's entities evenly around that player
+		let mut indices: std::collections::HashMap<Entity, usize> =
+			std::collections::HashMap::new();
+		for (mut behavior, owner) in all_orbiting.iter_mut() {
+			let index = indices.entry(owner.0).or_insert(0);
+			behavior.angle = (*index as f32 / current_counts[&owner.0] as f32) * 2.0 * PI;
+			*index += 1;
 		}
+
+		count_tracker.0 = current_counts;
 	}
 }
 
 // Generic update system for projectile spawners
+#[allow(clippy::too_many_arguments)]
 pub fn update_projectile_spawners(
 	mut commands: Commands,
 	mut spawner_query: ProjectileSpawnerQuery,
@@ -113,110 +219,153 @@ pub fn update_projectile_spawners(
 	>,
 	enemy_query: Query<&Transform, With<crate::behaviors::EnemyTag>>,
 	mut player_energy_query: Query<&mut crate::behaviors::PlayerEnergy, With<crate::behaviors::PlayerTag>>,
-	active_weapon: Res<crate::weapons::ActiveWeaponState>,
+	active_states: Query<&crate::weapons::ActiveWeaponState>,
+	player_stats: Query<&crate::player::PlayerStats>,
 	time: Res<Time<Virtual>>,
 ) {
 	use crate::behaviors::*;
 
-	for (spawner_transform, mut spawner, is_player_tag, is_enemy, weapon_slot) in
-		spawner_query.iter_mut()
+	for (
+		spawner_entity,
+		spawner_transform,
+		mut spawner,
+		is_player_tag,
+		is_enemy,
+		is_deployed,
+		weapon_slot,
+		owner,
+	) in spawner_query.iter_mut()
 	{
 		// Determine if this belongs to the player (for targeting logic)
-		let is_player_weapon = weapon_slot.is_some() || is_player_tag;
+		let is_player_weapon = weapon_slot.is_some() || is_player_tag || is_deployed;
+		let stats =
+			crate::player::PlayerStats::for_owner(&player_stats, owner.map(|owner| owner.0));
+
+		// Spirals keep turning from their own starting angle so they don't line up
+		if let SpawnLogic::Spiral { rotation_speed, .. } = spawner.spawn_logic {
+			spawner.spiral_angle =
+				(spawner.spiral_angle + rotation_speed * time.delta_secs()) % (2.0 * PI);
+		}
 
 		// Always tick cooldown if not finished (actively cooling down)
 		if !spawner.cooldown.is_finished() {
-			spawner.cooldown.tick(time.delta());
+			spawner
+				.cooldown
+				.tick(time.delta().mul_f32(stats.cooldown_speed()));
 			continue; // Skip to next weapon while cooling down
 		}
 
-		// If this is a player weapon with WeaponSlot::Ranged, check if it's active before firing
-		if let Some(WeaponSlot::Ranged) = weapon_slot {
-			if active_weapon.active_slot != Some(WeaponSlot::Ranged) {
-				continue; // Skip firing if ranged weapon is not active
-			}
+		// If this is a player weapon bound to a slot, check if the slot is active before firing
+		if !crate::weapons::is_slot_active(&active_states, owner, weapon_slot) {
+			continue; // Skip firing if the weapon's slot is not active
 		}
 
-		// Cooldown is ready, try to fire
-		let spawn_direction = match &spawner.spawn_logic {
-			SpawnLogic::NearestEnemy => {
-				// For player weapons, target enemies. For enemy weapons, target player.
-				if is_player_weapon {
-					// Find nearest enemy (optionally within range)
-					let nearest_enemy = enemy_query
-						.iter()
-						.filter(|enemy_transform| {
-							// If fire_range is set, only consider enemies within range
-							if let Some(range) = spawner.fire_range {
-								let distance = spawner_transform
-									.translation
-									.distance(enemy_transform.translation);
-								distance <= range
-							} else {
-								true // No range limit
-							}
-						})
-						.min_by(|a, b| {
-							let dist_a = spawner_transform.translation.distance(a.translation);
-							let dist_b = spawner_transform.translation.distance(b.translation);
-							dist_a.partial_cmp(&dist_b).unwrap()
-						});
-
-					// If no enemy in range, don't fire
-					if let Some(enemy_transform) = nearest_enemy {
-						let direction = Vec2::new(
-							enemy_transform.translation.x - spawner_transform.translation.x,
-							enemy_transform.translation.y - spawner_transform.translation.y,
-						);
-						Some(direction.normalize())
+		// Nearest target in range: enemies for player weapons, the player for enemy weapons
+		let target_direction = if is_player_weapon {
+			// Find nearest enemy (optionally within range)
+			let nearest_enemy = enemy_query
+				.iter()
+				.filter(|enemy_transform| {
+					// If fire_range is set, only consider enemies within range
+					if let Some(range) = spawner.fire_range {
+						let distance = spawner_transform
+							.translation
+							.distance(enemy_transform.translation);
+						distance <= range
 					} else {
-						// No enemy in range, skip spawning projectile
-						None
+						true // No range limit
 					}
-				} else if is_enemy {
-					// Enemy targeting player
-					if let Ok(player_transform) = player_query.single() {
-						let direction = Vec2::new(
-							player_transform.translation.x - spawner_transform.translation.x,
-							player_transform.translation.y - spawner_transform.translation.y,
-						);
-						let distance = direction.length();
-
-						// Check fire range
-						if let Some(range) = spawner.fire_range {
-							if distance > range {
-								None
-							} else {
-								Some(direction.normalize())
-							}
-						} else {
-							Some(direction.normalize())
-						}
-					} else {
+				})
+				.min_by(|a, b| {
+					let dist_a = spawner_transform.translation.distance(a.translation);
+					let dist_b = spawner_transform.translation.distance(b.translation);
+					dist_a.partial_cmp(&dist_b).unwrap()
+				});
+
+			// If no enemy in range, don't fire
+			if let Some(enemy_transform) = nearest_enemy {
+				let direction = Vec2::new(
+					enemy_transform.translation.x - spawner_transform.translation.x,
+					enemy_transform.translation.y - spawner_transform.translation.y,
+				);
+				Some(direction.normalize())
+			} else {
+				// No enemy in range, skip spawning projectile
+				None
+			}
+		} else if is_enemy {
+			// Enemy targeting the nearest player
+			if let Some(player_position) = crate::movement::nearest_translation(
+				spawner_transform.translation,
+				player_query.iter(),
+			) {
+				let direction = Vec2::new(
+					player_position.x - spawner_transform.translation.x,
+					player_position.y - spawner_transform.translation.y,
+				);
+				let distance = direction.length();
+
+				// Check fire range
+				if let Some(range) = spawner.fire_range {
+					if distance > range {
 						None
+					} else {
+						Some(direction.normalize())
 					}
 				} else {
-					None
+					Some(direction.normalize())
 				}
+			} else {
+				None
 			}
-			SpawnLogic::PlayerDirection => Some(Vec2::new(1.0, 0.0)), // Could be enhanced with actual player direction
+		} else {
+			None
+		};
+
+		// Amount fans single shots out and adds projectiles to patterns
+		let volley = |direction: Vec2| {
+			spread_directions(
+				direction,
+				1 + stats.amount,
+				crate::constants::PROJECTILE_AMOUNT_SPREAD_ANGLE * stats.amount as f32,
+			)
+		};
+
+		// Cooldown is ready, try to fire
+		let spawn_directions: Vec<Vec2> = match &spawner.spawn_logic {
+			SpawnLogic::NearestEnemy => target_direction.map(volley).unwrap_or_default(),
+			SpawnLogic::PlayerDirection => volley(Vec2::new(1.0, 0.0)), // Could be enhanced with actual player direction
 			SpawnLogic::Fixed(x, y) => {
 				let direction = Vec2::new(*x, *y);
 				if direction.length_squared() > 0.0 {
-					Some(direction.normalize())
+					volley(direction.normalize())
 				} else {
-					Some(Vec2::new(1.0, 0.0))
+					volley(Vec2::new(1.0, 0.0))
 				}
 			}
+			// Patterns only fire while a target is in range
+			SpawnLogic::Radial { count } => target_direction
+				.map(|_| radial_directions(count + stats.amount, 0.0))
+				.unwrap_or_default(),
+			SpawnLogic::Spiral { count, .. } => target_direction
+				.map(|_| radial_directions(count + stats.amount, spawner.spiral_angle))
+				.unwrap_or_default(),
+			SpawnLogic::AimedSpread { count, angle } => target_direction
+				.map(|direction| {
+					spread_directions(direction, count + stats.amount, angle.to_radians())
+				})
+				.unwrap_or_default(),
 		};
 
-		let Some(direction) = spawn_direction else {
+		if spawn_directions.is_empty() {
 			continue;
-		};
+		}
 
-		// Check energy for player weapons
+		// Check the owner's energy for player weapons
 		if is_player_weapon {
-			if let Ok(mut player_energy) = player_energy_query.single_mut() {
+			if let Some(mut player_energy) =
+				owner.and_then(|owner| player_energy_query.get_mut(owner.0).ok())
+			{
 				if player_energy.current < spawner.energy_cost {
 					continue; // Not enough energy, skip firing
 				}
@@ -227,10 +376,6 @@ pub fn update_projectile_spawners(
 		// Reset cooldown after firing
 		spawner.cooldown.reset();
 
-		// Spawn projectile
-		let template = &spawner.projectile_template;
-		let angle = direction.y.atan2(direction.x);
-
 		// Determine target filter based on who's spawning
 		let target_filter = if is_player_weapon {
 			TargetFilter::Enemies
@@ -238,32 +383,512 @@ pub fn update_projectile_spawners(
 			TargetFilter::Player
 		};
 
-		commands.spawn((
+		// Spawn one projectile per direction
+		let template = &spawner.projectile_template;
+		for direction in spawn_directions {
+			let angle = direction.y.atan2(direction.x);
+
+			let mut projectile_commands = commands.spawn((
+				Sprite {
+					color: Color::srgb(template.color.0, template.color.1, template.color.2),
+					custom_size: Some(
+						Vec2::new(template.size.0, template.size.1) * stats.area_multiplier(),
+					),
+					..default()
+				},
+				Transform::from_xyz(
+					spawner_transform.translation.x + direction.x * 30.0,
+					spawner_transform.translation.y + direction.y * 30.0,
+					0.0,
+				)
+				.with_rotation(Quat::from_rotation_z(angle)),
+				crate::physics::Velocity {
+					x: direction.x * template.speed,
+					y: direction.y * template.speed,
+				},
+				DespawnOnTimer {
+					timer: Timer::from_seconds(template.lifetime, TimerMode::Once),
+				},
+				ProjectilePlatformCollision {
+					mode: template.platform_collision,
+					bounces: 0,
+				},
+				ProjectileTag,
+			));
+
+			// Hits scale with the firing player's stats
+			if let Some(owner) = owner {
+				projectile_commands.insert(*owner);
+			}
+
+			match template.motion {
+				ProjectileMotion::Straight => {
+					projectile_commands.insert(DamageOnContact {
+						damage: template.damage,
+						damage_type: DamageType::OneTime,
+						targets: target_filter,
+					});
+				}
+				ProjectileMotion::Boomerang {
+					max_distance,
+					cooldown_refund,
+				} => {
+					// Constant deceleration that brings the projectile to rest exactly at max_distance
+					let deceleration = template.speed * template.speed / (2.0 * max_distance);
+					projectile_commands.insert(BoomerangProjectile {
+						owner: spawner_entity,
+						damage: template.damage,
+						targets: target_filter,
+						speed: template.speed,
+						deceleration,
+						returning: false,
+						cooldown_refund,
+						hit_entities: Vec::new(),
+					});
+				}
+			}
+		}
+	}
+}
+
+// Evenly spaced directions around a full circle, starting at the given angle
+fn radial_directions(count: u32, start_angle: f32) -> Vec<Vec2> {
+	(0..count)
+		.map(|i| Vec2::from_angle(start_angle + i as f32 / count as f32 * 2.0 * PI))
+		.collect()
+}
+
+// Evenly spaced directions fanned across spread_angle, centered on the aim direction
+fn spread_directions(aim_direction: Vec2, count: u32, spread_angle: f32) -> Vec<Vec2> {
+	if count <= 1 {
+		return vec![aim_direction];
+	}
+
+	let aim_angle = aim_direction.y.atan2(aim_direction.x);
+	let step = spread_angle / (count - 1) as f32;
+	(0..count)
+		.map(|i| Vec2::from_angle(aim_angle - spread_angle / 2.0 + i as f32 * step))
+		.collect()
+}
+
+// Boomerang projectiles: decelerate on the way out, home back to their owner, refund cooldown on catch
+pub fn update_boomerang_projectiles(
+	mut commands: Commands,
+	mut boomerang_query: BoomerangQuery,
+	mut owner_query: BoomerangOwnerQuery,
+	mut target_query: BoomerangTargetQuery,
+	mut combat_stats: crate::combat::CombatStats,
+	time: Res<Time<Virtual>>,
+) {
+	use crate::behaviors::TargetFilter;
+
+	let delta = time.delta_secs();
+
+	for (entity, mut transform, mut velocity, sprite, mut boomerang, player) in
+		boomerang_query.iter_mut()
+	{
+		// Projectiles whose owner is gone have nothing to return to
+		let Ok((owner_transform, mut owner_spawner)) = owner_query.get_mut(boomerang.owner) else {
+			commands.entity(entity).despawn();
+			continue;
+		};
+
+		let current_velocity = Vec2::new(velocity.x, velocity.y);
+		let to_owner = owner_transform.translation.truncate() - transform.translation.truncate();
+
+		let new_velocity = if !boomerang.returning {
+			let speed = (current_velocity.length() - boomerang.deceleration * delta).max(0.0);
+			if speed <= 0.0 {
+				// Turning around starts a new leg, so every target can be hit again
+				boomerang.returning = true;
+				boomerang.hit_entities.clear();
+			}
+			current_velocity.normalize_or_zero() * speed
+		} else {
+			if to_owner.length() <= crate::constants::BOOMERANG_CATCH_DISTANCE {
+				// Refund only the time still left, so a ready weapon stays ready
+				let cooldown = &mut owner_spawner.cooldown;
+				if !cooldown.is_finished() {
+					let refund = std::time::Duration::from_secs_f32(boomerang.cooldown_refund);
+					let refund = cooldown.remaining().min(refund);
+					cooldown.tick(refund);
+				}
+				commands.entity(entity).despawn();
+				continue;
+			}
+
+			// Re-aim every frame so the projectile homes in on its (moving) owner
+			let speed =
+				(current_velocity.length() + boomerang.deceleration * delta).min(boomerang.speed);
+			to_owner.normalize_or_zero() * speed
+		};
+
+		velocity.x = new_velocity.x;
+		velocity.y = new_velocity.y;
+		transform.rotate_z(crate::constants::BOOMERANG_SPIN_SPEED * delta);
+
+		let size = sprite.custom_size.unwrap_or(Vec2::ONE);
+
+		for (target_entity, target_transform, target_sprite, mut damageable, is_enemy, is_player) in
+			target_query.iter_mut()
+		{
+			let target_matches = match boomerang.targets {
+				TargetFilter::Enemies => is_enemy,
+				TargetFilter::Player => is_player,
+				TargetFilter::All => true,
+			};
+
+			if !target_matches || boomerang.hit_entities.contains(&target_entity) {
+				continue;
+			}
+
+			if super::melee::check_collision(
+				transform.translation,
+				size,
+				target_transform.translation,
+				target_sprite.custom_size.unwrap_or(Vec2::ONE),
+			) {
+				damageable.health -= combat_stats.hit(
+					player.map(|player| player.0),
+					target_entity,
+					boomerang.damage,
+				);
+				boomerang.hit_entities.push(target_entity);
+			}
+		}
+	}
+}
+
+// Continuous beam weapons: raycast toward the nearest enemy, damage everything along the beam
+#[allow(clippy::too_many_arguments)]
+pub fn update_beam_emitters(
+	mut commands: Commands,
+	mut emitter_query: BeamEmitterQuery,
+	mut visual_query: BeamVisualQuery,
+	mut target_query: BeamTargetQuery,
+	ground_query: BeamGroundQuery,
+	mut player_energy_query: Query<
+		&mut crate::behaviors::PlayerEnergy,
+		With<crate::behaviors::PlayerTag>,
+	>,
+	active_states: Query<&crate::weapons::ActiveWeaponState>,
+	mut combat_stats: crate::combat::CombatStats,
+	time: Res<Time<Virtual>>,
+) {
+	use crate::behaviors::*;
+
+	let delta = time.delta_secs();
+
+	for (emitter_entity, emitter_transform, mut beam, weapon_slot, owner) in
+		emitter_query.iter_mut()
+	{
+		let origin = emitter_transform.translation.truncate();
+
+		// Beam only fires while its slot is active
+		let slot_active = crate::weapons::is_slot_active(&active_states, owner, weapon_slot);
+
+		// Aim at the nearest enemy within range
+		let mut direction = if slot_active {
+			target_query
+				.iter()
+				.map(|(_, transform, _, _)| transform.translation.truncate() - origin)
+				.filter(|offset| offset.length() <= beam.range)
+				.min_by(|a, b| a.length().partial_cmp(&b.length()).unwrap())
+				.map(|offset| offset.normalize_or_zero())
+				.filter(|direction| *direction != Vec2::ZERO)
+		} else {
+			None
+		};
+
+		// Drain the owner's energy per second while firing; the beam shuts off when it runs out
+		if direction.is_some() {
+			if let Some(mut player_energy) =
+				owner.and_then(|owner| player_energy_query.get_mut(owner.0).ok())
+			{
+				let cost = beam.energy_per_second * delta;
+				if player_energy.current < cost {
+					direction = None;
+				} else {
+					player_energy.current -= cost;
+				}
+			}
+		}
+
+		let Some(direction) = direction else {
+			if let Some(visual) = beam.visual.take() {
+				commands.entity(visual).despawn();
+			}
+			continue;
+		};
+
+		// Clip the beam at the first platform unless it pierces
+		let mut length = beam.range;
+		if !beam.piercing {
+			for (ground_transform, ground_sprite) in ground_query.iter() {
+				let half_size = ground_sprite.custom_size.unwrap_or(Vec2::ONE) / 2.0;
+				if let Some(distance) = ray_aabb_distance(
+					origin,
+					direction,
+					ground_transform.translation.truncate(),
+					half_size,
+				) {
+					length = length.min(distance);
+				}
+			}
+		}
+
+		// Damage every enemy the beam passes through
+		let owner = owner.map(|owner| owner.0);
+		let width = beam.width * combat_stats.owner_stats(owner).area_multiplier();
+		let half_width = width / 2.0;
+		for (target_entity, target_transform, target_sprite, mut damageable) in
+			target_query.iter_mut()
+		{
+			let half_size =
+				target_sprite.custom_size.unwrap_or(Vec2::ONE) / 2.0 + Vec2::splat(half_width);
+			let hit = ray_aabb_distance(
+				origin,
+				direction,
+				target_transform.translation.truncate(),
+				half_size,
+			)
+			.is_some_and(|distance| distance <= length);
+
+			if hit {
+				damageable.health -=
+					combat_stats.hit_over_time(owner, target_entity, beam.damage_per_second, delta);
+			}
+		}
+
+		// Stretch the beam sprite from the emitter to the end point
+		let local_transform = Transform::from_translation((direction * length / 2.0).extend(0.0))
+			.with_rotation(Quat::from_rotation_z(direction.y.atan2(direction.x)));
+		let size = Vec2::new(length, width);
+
+		match beam
+			.visual
+			.and_then(|visual| visual_query.get_mut(visual).ok())
+		{
+			Some((mut visual_transform, mut visual_sprite)) => {
+				*visual_transform = local_transform;
+				visual_sprite.custom_size = Some(size);
+			}
+			None => {
+				let visual = commands
+					.spawn((
+						Sprite {
+							color: Color::srgba(beam.color.0, beam.color.1, beam.color.2, 0.8),
+							custom_size: Some(size),
+							..default()
+						},
+						local_transform,
+						BeamVisual,
+					))
+					.id();
+				commands.entity(emitter_entity).add_child(visual);
+				beam.visual = Some(visual);
+			}
+		}
+	}
+}
+
+// Distance along a ray to an axis-aligned box, or None if the ray misses it
+fn ray_aabb_distance(origin: Vec2, direction: Vec2, center: Vec2, half_size: Vec2) -> Option<f32> {
+	let min = center - half_size - origin;
+	let max = center + half_size - origin;
+	let mut t_near = f32::NEG_INFINITY;
+	let mut t_far = f32::INFINITY;
+
+	for axis in 0..2 {
+		// A ray parallel to an axis only hits if it starts between that axis's edges
+		if direction[axis] == 0.0 {
+			if min[axis] > 0.0 || max[axis] < 0.0 {
+				return None;
+			}
+			continue;
+		}
+
+		let t1 = min[axis] / direction[axis];
+		let t2 = max[axis] / direction[axis];
+		t_near = t_near.max(t1.min(t2));
+		t_far = t_far.min(t1.max(t2));
+	}
+
+	if t_far < 0.0 || t_near > t_far {
+		None
+	} else {
+		Some(t_near.max(0.0))
+	}
+}
+
+// Drops deployables (turrets, mines) at the owner's position when their cooldown is ready
+pub fn update_deployers(
+	mut commands: Commands,
+	mut deployer_query: Query<(
+		Entity,
+		&mut crate::behaviors::Deployer,
+		&crate::behaviors::WeaponOwner,
+	)>,
+	deployed_query: Query<(
+		Entity,
+		&crate::behaviors::Deployed,
+		&crate::behaviors::DespawnOnTimer,
+	)>,
+	mut player_query: Query<
+		(&Transform, &mut crate::behaviors::PlayerEnergy),
+		With<crate::behaviors::PlayerTag>,
+	>,
+	player_stats: Query<&crate::player::PlayerStats>,
+	time: Res<Time<Virtual>>,
+) {
+	use crate::behaviors::*;
+
+	for (deployer_entity, mut deployer, owner) in deployer_query.iter_mut() {
+		if !deployer.cooldown.is_finished() {
+			let stats = crate::player::PlayerStats::for_owner(&player_stats, Some(owner.0));
+			deployer
+				.cooldown
+				.tick(time.delta().mul_f32(stats.cooldown_speed()));
+			continue;
+		}
+
+		let Ok((player_transform, mut player_energy)) = player_query.get_mut(owner.0) else {
+			continue;
+		};
+
+		if deployer.max_active == 0 || player_energy.current < deployer.energy_cost {
+			continue;
+		}
+		player_energy.current -= deployer.energy_cost;
+		deployer.cooldown.reset();
+
+		// Make room by removing the oldest deployment when at the cap
+		let active: Vec<_> = deployed_query
+			.iter()
+			.filter(|(_, deployed, _)| deployed.owner == deployer_entity)
+			.collect();
+		if active.len() as u32 >= deployer.max_active {
+			if let Some((oldest, _, _)) = active
+				.iter()
+				.max_by_key(|(_, _, despawn_timer)| despawn_timer.timer.elapsed())
+			{
+				commands.entity(*oldest).despawn();
+			}
+		}
+
+		let mut entity_commands = commands.spawn((
 			Sprite {
-				color: Color::srgb(template.color.0, template.color.1, template.color.2),
-				custom_size: Some(Vec2::new(template.size.0, template.size.1)),
+				color: Color::srgb(deployer.color.0, deployer.color.1, deployer.color.2),
+				custom_size: Some(Vec2::new(deployer.size.0, deployer.size.1)),
 				..default()
 			},
 			Transform::from_xyz(
-				spawner_transform.translation.x + direction.x * 30.0,
-				spawner_transform.translation.y + direction.y * 30.0,
-				0.0,
-			)
-			.with_rotation(Quat::from_rotation_z(angle)),
-			crate::physics::Velocity {
-				x: direction.x * template.speed,
-				y: direction.y * template.speed,
+				player_transform.translation.x,
+				player_transform.translation.y,
+				0.5,
+			),
+			Deployed {
+				owner: deployer_entity,
+			},
+			*owner,
+			DespawnOnTimer {
+				timer: Timer::from_seconds(deployer.lifetime, TimerMode::Once),
+			},
+		));
+		super::apply_weapon_behaviors(&mut entity_commands, &deployer.behaviors);
+	}
+}
+
+// Summons allied minions at the owner's position while below the max summon count
+pub fn update_summoners(
+	mut commands: Commands,
+	mut summoner_query: Query<(
+		Entity,
+		&mut crate::behaviors::Summoner,
+		&crate::behaviors::WeaponOwner,
+	)>,
+	summoned_query: Query<&crate::behaviors::Summoned>,
+	mut player_query: Query<
+		(&Transform, &mut crate::behaviors::PlayerEnergy),
+		With<crate::behaviors::PlayerTag>,
+	>,
+	player_stats: Query<&crate::player::PlayerStats>,
+	time: Res<Time<Virtual>>,
+) {
+	use crate::behaviors::*;
+
+	for (summoner_entity, mut summoner, owner) in summoner_query.iter_mut() {
+		let stats = crate::player::PlayerStats::for_owner(&player_stats, Some(owner.0));
+		if !summoner.cooldown.is_finished() {
+			summoner
+				.cooldown
+				.tick(time.delta().mul_f32(stats.cooldown_speed()));
+			continue;
+		}
+
+		let Ok((player_transform, mut player_energy)) = player_query.get_mut(owner.0) else {
+			continue;
+		};
+
+		let alive = summoned_query
+			.iter()
+			.filter(|summoned| summoned.owner == summoner_entity)
+			.count() as u32;
+		if alive >= summoner.max_summons + stats.amount
+			|| player_energy.current < summoner.energy_cost
+		{
+			continue; // Stay ready until a slot frees up
+		}
+		player_energy.current -= summoner.energy_cost;
+		summoner.cooldown.reset();
+
+		// Minion contact damage follows the summoner's upgraded damage
+		let behaviors: Vec<BehaviorData> = summoner
+			.behaviors
+			.iter()
+			.cloned()
+			.map(|behavior| match behavior {
+				BehaviorData::DamageOnContact {
+					damage_type,
+					targets,
+					..
+				} => BehaviorData::DamageOnContact {
+					damage: summoner.damage,
+					damage_type,
+					targets,
+				},
+				other => other,
+			})
+			.collect();
+
+		// No Collider: minions overlap the player and enemies instead of shoving them
+		let mut entity_commands = commands.spawn((
+			Sprite {
+				color: Color::srgb(summoner.color.0, summoner.color.1, summoner.color.2),
+				custom_size: Some(Vec2::new(summoner.size.0, summoner.size.1)),
+				..default()
+			},
+			Transform::from_xyz(
+				player_transform.translation.x,
+				player_transform.translation.y,
+				0.5,
+			),
+			AllyTag,
+			Summoned {
+				owner: summoner_entity,
 			},
-			DamageOnContact {
-				damage: template.damage,
-				damage_type: DamageType::OneTime,
-				targets: target_filter,
+			*owner,
+			Damageable {
+				health: summoner.health,
+				max_health: summoner.health,
 			},
 			DespawnOnTimer {
-				timer: Timer::from_seconds(template.lifetime, TimerMode::Once),
+				timer: Timer::from_seconds(summoner.lifetime, TimerMode::Once),
 			},
-			ProjectileTag,
+			crate::physics::Velocity { x: 0.0, y: 0.0 },
+			crate::physics::Grounded(false),
 		));
+		super::apply_weapon_behaviors(&mut entity_commands, &behaviors);
 	}
 }
 
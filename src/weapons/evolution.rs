@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+
+/// Placed on a freshly spawned evolved weapon until it has taken over the base weapon's
+/// slot and cooldown bar.
+#[derive(Component)]
+pub struct PendingEvolution {
+	pub base_entity: Entity,
+	pub base_weapon_id: String,
+}
+
+type BaseWeaponQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'static crate::behaviors::WeaponId,
+		Option<&'static crate::behaviors::WeaponSlot>,
+		&'static crate::behaviors::WeaponOwner,
+	),
+	Without<PendingEvolution>,
+>;
+
+// Replace base weapons with their evolved versions, keeping the base weapon's slot and UI position
+pub fn finalize_weapon_evolutions(
+	mut commands: Commands,
+	evolved_query: Query<(
+		Entity,
+		&PendingEvolution,
+		&super::WeaponName,
+		&crate::behaviors::WeaponOwner,
+	)>,
+	base_query: BaseWeaponQuery,
+	mut bar_query: Query<(&mut super::WeaponCooldownBar, Option<&mut Text>)>,
+) {
+	for (evolved_entity, pending, weapon_name, owner) in evolved_query.iter() {
+		// Keep the base weapon's activation slot
+		if let Ok((_, _, Some(slot), _)) = base_query.get(pending.base_entity) {
+			commands.entity(evolved_entity).insert(slot.clone());
+		}
+
+		// Hand the base weapon's cooldown bar over to the evolved weapon
+		let mut has_bar = false;
+		for (mut bar, text) in bar_query.iter_mut() {
+			if bar.weapon_entity != pending.base_entity {
+				continue;
+			}
+			bar.weapon_entity = evolved_entity;
+			has_bar = true;
+			if let Some(mut text) = text {
+				**text = weapon_name.0.clone();
+			}
+		}
+		if has_bar {
+			commands
+				.entity(evolved_entity)
+				.insert(super::ui::HasCooldownUI);
+		}
+
+		// Remove every instance of the owner's base weapon (e.g. additional orbiting blades)
+		for (entity, weapon_id, _, weapon_owner) in base_query.iter() {
+			if weapon_id.0 == pending.base_weapon_id && weapon_owner == owner {
+				commands.entity(entity).despawn();
+			}
+		}
+
+		commands.entity(evolved_entity).remove::<PendingEvolution>();
+	}
+}
@@ -17,83 +17,134 @@ type NonChargingPlayerQuery<'w, 's> = Query<
 	),
 >;
 
+type MeleeWeaponQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		&'static mut crate::behaviors::MeleeAttack,
+		Option<&'static mut crate::behaviors::MeleeCombo>,
+		Option<&'static crate::behaviors::WeaponSlot>,
+		&'static crate::behaviors::WeaponOwner,
+	),
+	With<crate::behaviors::FollowPlayer>,
+>;
+
 pub fn detect_melee_targets(
 	mut commands: Commands,
-	mut melee_query: Query<
-		&mut crate::behaviors::MeleeAttack,
-		With<crate::behaviors::FollowPlayer>,
-	>,
+	mut melee_query: MeleeWeaponQuery,
 	player_query: NonChargingPlayerQuery,
 	attack_query: Query<&crate::behaviors::MeleeAttackState, With<crate::behaviors::PlayerTag>>,
 	enemy_query: Query<&Transform, With<crate::behaviors::EnemyTag>>,
 	mut player_energy_query: PlayerEnergyQuery,
-	(active_weapon, time): (Res<crate::weapons::ActiveWeaponState>, Res<Time<Virtual>>),
+	(active_states, player_stats, time): (
+		Query<&crate::weapons::ActiveWeaponState>,
+		Query<&crate::player::PlayerStats>,
+		Res<Time<Virtual>>,
+	),
 ) {
 	use crate::behaviors::*;
 
-	// Don't trigger new melee attacks while already attacking
-	if !attack_query.is_empty() {
-		return;
-	}
+	for (mut melee, mut combo, weapon_slot, owner) in melee_query.iter_mut() {
+		let Ok((player_entity, player_transform)) = player_query.get(owner.0) else {
+			continue;
+		};
 
-	if let Ok((player_entity, player_transform)) = player_query.single() {
-		for mut melee in melee_query.iter_mut() {
-			// Always tick cooldown if it's not finished (actively cooling down)
-			if !melee.cooldown.is_finished() {
-				melee.cooldown.tick(time.delta());
-			}
+		// Don't trigger new melee attacks while the owner is already attacking
+		if attack_query.contains(player_entity) {
+			continue;
+		}
 
-			// Only allow attacking if melee weapon is active
-			if active_weapon.active_slot != Some(WeaponSlot::Melee) {
-				continue;
+		let owner_stats = crate::player::PlayerStats::for_owner(&player_stats, Some(owner.0));
+
+		// Always tick cooldown if it's not finished (actively cooling down)
+		if !melee.cooldown.is_finished() {
+			melee
+				.cooldown
+				.tick(time.delta().mul_f32(owner_stats.cooldown_speed()));
+		}
+
+		// The combo resets if the window since the last attack runs out
+		if let Some(ref mut combo) = combo {
+			if !combo.window.is_finished() {
+				combo.window.tick(time.delta());
+				if combo.window.is_finished() {
+					combo.step = 0;
+				}
 			}
+		}
 
-			// Find nearest enemy within detection range
-			let nearest_enemy = enemy_query
-				.iter()
-				.filter(|enemy_transform| {
-					player_transform
-						.translation
-						.distance(enemy_transform.translation)
-						<= melee.detection_range
-				})
-				.min_by(|a, b| {
-					let dist_a = player_transform.translation.distance(a.translation);
-					let dist_b = player_transform.translation.distance(b.translation);
-					dist_a.partial_cmp(&dist_b).unwrap()
-				});
+		// Only allow attacking if the weapon's slot is active
+		if !crate::weapons::is_slot_active(&active_states, Some(owner), weapon_slot) {
+			continue;
+		}
 
-			// Only attack if cooldown is ready AND there's an enemy in range
-			if let Some(enemy_transform) = nearest_enemy {
-				if melee.cooldown.is_finished() {
-					// Check if player has enough energy
-					if let Ok(mut player_energy) = player_energy_query.single_mut() {
-						if player_energy.current < melee.energy_cost {
-							continue; // Not enough energy, skip attack
-						}
-						player_energy.current -= melee.energy_cost;
+		// Find nearest enemy within detection range
+		let nearest_enemy = enemy_query
+			.iter()
+			.filter(|enemy_transform| {
+				player_transform
+					.translation
+					.distance(enemy_transform.translation)
+					<= melee.detection_range
+			})
+			.min_by(|a, b| {
+				let dist_a = player_transform.translation.distance(a.translation);
+				let dist_b = player_transform.translation.distance(b.translation);
+				dist_a.partial_cmp(&dist_b).unwrap()
+			});
+
+		// Only attack if cooldown is ready AND there's an enemy in range
+		if let Some(enemy_transform) = nearest_enemy {
+			if melee.cooldown.is_finished() {
+				// Check if the owner has enough energy
+				if let Ok(mut player_energy) = player_energy_query.get_mut(player_entity) {
+					if player_energy.current < melee.energy_cost {
+						continue; // Not enough energy, skip attack
 					}
-
-					melee.cooldown.reset();
-
-					// Calculate initial attack direction
-					let attack_direction = Vec2::new(
-						enemy_transform.translation.x - player_transform.translation.x,
-						enemy_transform.translation.y - player_transform.translation.y,
-					)
-					.normalize();
-
-					// Add MeleeAttackState to player
-					commands.entity(player_entity).insert(MeleeAttackState {
-						attack_timer: Timer::from_seconds(melee.attack_duration, TimerMode::Once),
-						damage: melee.damage,
-						stun_duration: melee.stun_duration,
-						knockback_force: melee.knockback_force,
-						hitbox_size: melee.hitbox_size,
-						hitbox_color: melee.hitbox_color,
-						attack_direction,
-					});
+					player_energy.current -= melee.energy_cost;
 				}
+
+				melee.cooldown.reset();
+
+				// Calculate initial attack direction
+				let attack_direction = Vec2::new(
+					enemy_transform.translation.x - player_transform.translation.x,
+					enemy_transform.translation.y - player_transform.translation.y,
+				)
+				.normalize();
+
+				// Combo hits scale the base attack; weapons without a combo always use it as-is
+				let (damage, knockback_force, hitbox_size, finisher) = match combo {
+					Some(ref mut combo) => {
+						let hit = &combo.hits[combo.step];
+						let stats = (
+							melee.damage * hit.damage_multiplier,
+							melee.knockback_force * hit.knockback_multiplier,
+							hit.hitbox_size,
+							hit.finisher,
+						);
+						combo.step = (combo.step + 1) % combo.hits.len();
+						combo.window.reset();
+						stats
+					}
+					None => (melee.damage, melee.knockback_force, melee.hitbox_size, None),
+				};
+
+				// Area grows the hitbox of every swing
+				let area = owner_stats.area_multiplier();
+				let hitbox_size = (hitbox_size.0 * area, hitbox_size.1 * area);
+
+				// Add MeleeAttackState to player
+				commands.entity(player_entity).insert(MeleeAttackState {
+					attack_timer: Timer::from_seconds(melee.attack_duration, TimerMode::Once),
+					damage,
+					stun_duration: melee.stun_duration,
+					knockback_force,
+					hitbox_size,
+					hitbox_color: melee.hitbox_color,
+					attack_direction,
+					finisher,
+				});
 			}
 		}
 	}
@@ -116,14 +167,16 @@ pub fn execute_melee_attack(
 ) {
 	use crate::behaviors::*;
 
-	if let Ok((player_entity, player_transform, mut velocity, mut attack_state)) =
-		player_query.single_mut()
+	for (player_entity, player_transform, mut velocity, mut attack_state) in player_query.iter_mut()
 	{
 		// Tick attack timer
 		attack_state.attack_timer.tick(time.delta());
 
-		// If hitbox doesn't exist yet, spawn it
-		if hitbox_query.is_empty() {
+		// If this player's hitbox doesn't exist yet, spawn it
+		if !hitbox_query
+			.iter()
+			.any(|hitbox| hitbox.owner == player_entity)
+		{
 			let angle = attack_state
 				.attack_direction
 				.y
@@ -145,10 +198,12 @@ pub fn execute_melee_attack(
 				Transform::from_translation(player_transform.translation)
 					.with_rotation(Quat::from_rotation_z(angle)),
 				MeleeHitbox {
+					owner: player_entity,
 					damage: attack_state.damage,
 					stun_duration: attack_state.stun_duration,
 					knockback_force: attack_state.knockback_force,
 					hit_entities: Vec::new(),
+					finisher: attack_state.finisher,
 				},
 			));
 		}
@@ -162,7 +217,11 @@ pub fn execute_melee_attack(
 			dist_a.partial_cmp(&dist_b).unwrap()
 		});
 
-		if let Some(enemy_transform) = nearest_enemy {
+		if let Some(ComboFinisher::GroundSlam { slam_speed }) = attack_state.finisher {
+			// Ground slam drives straight down instead of tracking
+			velocity.x = 0.0;
+			velocity.y = -slam_speed;
+		} else if let Some(enemy_transform) = nearest_enemy {
 			let direction = Vec2::new(
 				enemy_transform.translation.x - player_transform.translation.x,
 				enemy_transform.translation.y - player_transform.translation.y,
@@ -208,6 +267,7 @@ type MeleePlayerQuery<'w, 's> = Query<
 	&'static Transform,
 	(
 		With<crate::behaviors::PlayerTag>,
+		With<crate::behaviors::MeleeAttackState>,
 		Without<crate::behaviors::MeleeHitbox>,
 		Without<crate::behaviors::EnemyTag>,
 	),
@@ -232,71 +292,81 @@ pub fn update_melee_hitboxes(
 	mut commands: Commands,
 	mut hitbox_query: MeleeHitboxQuery,
 	player_query: MeleePlayerQuery,
-	attack_state_query: Query<
-		&crate::behaviors::MeleeAttackState,
-		With<crate::behaviors::PlayerTag>,
-	>,
 	mut enemy_query: MeleeEnemyQuery,
+	mut combat_stats: crate::combat::CombatStats,
 ) {
 	use crate::behaviors::*;
 
-	// Remove hitboxes if attack state is gone
-	if attack_state_query.is_empty() {
-		for (hitbox_entity, _, _, _) in hitbox_query.iter() {
+	// Update hitbox position and check for hits
+	for (hitbox_entity, mut hitbox_transform, hitbox_sprite, mut hitbox) in hitbox_query.iter_mut()
+	{
+		// Remove hitboxes whose player's attack state is gone
+		let Ok(player_transform) = player_query.get(hitbox.owner) else {
 			commands.entity(hitbox_entity).despawn();
-		}
-		return;
-	}
+			continue;
+		};
 
-	// Update hitbox position and check for hits
-	if let Ok(player_transform) = player_query.single() {
-		for (_hitbox_entity, mut hitbox_transform, hitbox_sprite, mut hitbox) in
-			hitbox_query.iter_mut()
+		// Keep hitbox centered on player
+		hitbox_transform.translation = player_transform.translation;
+
+		let hitbox_size = hitbox_sprite.custom_size.unwrap_or(Vec2::ONE);
+
+		// Check collision with all enemies
+		for (enemy_entity, enemy_transform, enemy_sprite, mut enemy_velocity, mut damageable) in
+			enemy_query.iter_mut()
 		{
-			// Keep hitbox centered on player
-			hitbox_transform.translation = player_transform.translation;
-
-			let hitbox_size = hitbox_sprite.custom_size.unwrap_or(Vec2::ONE);
-
-			// Check collision with all enemies
-			for (enemy_entity, enemy_transform, enemy_sprite, mut enemy_velocity, mut damageable) in
-				enemy_query.iter_mut()
-			{
-				// Skip if already hit this entity
-				if hitbox.hit_entities.contains(&enemy_entity) {
-					continue;
+			// Skip if already hit this entity
+			if hitbox.hit_entities.contains(&enemy_entity) {
+				continue;
+			}
+
+			let enemy_size = enemy_sprite.custom_size.unwrap_or(Vec2::ONE);
+
+			// Check AABB collision
+			if check_collision(
+				hitbox_transform.translation,
+				hitbox_size,
+				enemy_transform.translation,
+				enemy_size,
+			) {
+				// Apply damage
+				damageable.health -=
+					combat_stats.hit(Some(hitbox.owner), enemy_entity, hitbox.damage);
+
+				// Apply knockback
+				let knockback_direction = Vec2::new(
+					enemy_transform.translation.x - player_transform.translation.x,
+					enemy_transform.translation.y - player_transform.translation.y,
+				)
+				.normalize_or_zero();
+
+				match hitbox.finisher {
+					Some(ComboFinisher::Launcher { launch_force }) => {
+						enemy_velocity.x = knockback_direction.x * hitbox.knockback_force;
+						enemy_velocity.y = launch_force;
+					}
+					Some(ComboFinisher::GroundSlam { .. }) => {
+						let side = if knockback_direction.x < 0.0 {
+							-1.0
+						} else {
+							1.0
+						};
+						enemy_velocity.x = side * hitbox.knockback_force;
+						enemy_velocity.y = 0.0;
+					}
+					None => {
+						enemy_velocity.x = knockback_direction.x * hitbox.knockback_force;
+						enemy_velocity.y = knockback_direction.y * hitbox.knockback_force;
+					}
 				}
 
-				let enemy_size = enemy_sprite.custom_size.unwrap_or(Vec2::ONE);
+				// Apply stun
+				commands.entity(enemy_entity).insert(Stunned {
+					timer: Timer::from_seconds(hitbox.stun_duration, TimerMode::Once),
+				});
 
-				// Check AABB collision
-				if check_collision(
-					hitbox_transform.translation,
-					hitbox_size,
-					enemy_transform.translation,
-					enemy_size,
-				) {
-					// Apply damage
-					damageable.health -= hitbox.damage;
-
-					// Apply knockback
-					let knockback_direction = Vec2::new(
-						enemy_transform.translation.x - player_transform.translation.x,
-						enemy_transform.translation.y - player_transform.translation.y,
-					)
-					.normalize_or_zero();
-
-					enemy_velocity.x = knockback_direction.x * hitbox.knockback_force;
-					enemy_velocity.y = knockback_direction.y * hitbox.knockback_force;
-
-					// Apply stun
-					commands.entity(enemy_entity).insert(Stunned {
-						timer: Timer::from_seconds(hitbox.stun_duration, TimerMode::Once),
-					});
-
-					// Mark as hit
-					hitbox.hit_entities.push(enemy_entity);
-				}
+				// Mark as hit
+				hitbox.hit_entities.push(enemy_entity);
 			}
 		}
 	}
@@ -318,7 +388,7 @@ pub fn update_stunned_enemies(
 	}
 }
 
-fn check_collision(pos1: Vec3, size1: Vec2, pos2: Vec3, size2: Vec2) -> bool {
+pub(super) fn check_collision(pos1: Vec3, size1: Vec2, pos2: Vec3, size2: Vec2) -> bool {
 	let half_size1 = size1 / 2.0;
 	let half_size2 = size2 / 2.0;
 
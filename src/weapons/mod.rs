@@ -3,19 +3,26 @@ use bevy::{asset::AssetLoader, prelude::*};
 use serde::Deserialize;
 
 mod behaviors;
+mod evolution;
 mod melee;
+mod slots;
 mod ui;
 mod upgrades;
 
 pub use behaviors::{
-	redistribute_orbiting_entities, update_despawn_timers, update_following_entities,
-	update_orbiting_entities, update_projectile_spawners, OrbitingEntityCount,
+	redistribute_orbiting_entities, update_beam_emitters, update_boomerang_projectiles,
+	update_deployers, update_despawn_timers, update_following_entities, update_orbiting_entities,
+	update_projectile_spawners, update_summoners, OrbitingEntityCount,
 };
+pub use evolution::{finalize_weapon_evolutions, PendingEvolution};
 pub use melee::{
 	detect_melee_targets, execute_melee_attack, update_melee_hitboxes, update_stunned_enemies,
 };
+pub use slots::{
+	binding_label, initialize_weapon_slots, update_weapon_activation, WeaponSlotLoadout,
+};
 pub use ui::{spawn_weapon_cooldown_bars, update_weapon_cooldown_bars, WeaponCooldownBar};
-pub use upgrades::{apply_weapon_upgrades, sync_weapon_stats};
+pub use upgrades::{apply_weapon_level_tables, apply_weapon_upgrades, sync_weapon_stats};
 
 pub struct WeaponsPlugin;
 
@@ -35,6 +42,32 @@ pub struct WeaponData {
 	pub behaviors: Vec<BehaviorData>,
 	#[serde(default)]
 	pub upgrade_behaviors: Vec<crate::behaviors::UpgradeBehavior>,
+	#[serde(default)]
+	pub levels: Vec<crate::behaviors::UpgradeLevelData>,
+	#[serde(default)]
+	pub max_level: Option<u32>,
+	#[serde(default)]
+	pub slot: Option<String>, // Weapon slot name; defaults to "Melee"/"Ranged" from behaviors
+}
+
+impl WeaponData {
+	/// Highest level this weapon can reach, if capped. A level table implies its own cap.
+	pub fn max_level(&self) -> Option<u32> {
+		self.max_level
+			.or_else(|| (!self.levels.is_empty()).then_some(self.levels.len() as u32 + 1))
+	}
+
+	/// Slot that activates this weapon. Weapons without a slot are always active.
+	pub fn slot_name(&self) -> Option<String> {
+		self.slot.clone().or_else(|| {
+			self.behaviors.iter().find_map(|behavior| match behavior {
+				BehaviorData::MeleeAttack { .. } => Some("Melee".to_string()),
+				BehaviorData::ProjectileSpawner { .. } => Some("Ranged".to_string()),
+				BehaviorData::Beam { .. } => Some("Ranged".to_string()),
+				_ => None,
+			})
+		})
+	}
 }
 
 #[derive(Default)]
@@ -74,14 +107,35 @@ impl WeaponRegistry {
 	}
 }
 
-#[derive(Resource, Default)]
+/// Weapons owned by one player.
+#[derive(Component, Default)]
 pub struct WeaponInventory {
 	pub weapons: std::collections::HashMap<String, (Entity, u32)>, // weapon_id -> (entity, level)
 }
 
-#[derive(Resource, Default)]
+/// Weapon slots one player currently has switched on.
+#[derive(Component, Default)]
 pub struct ActiveWeaponState {
-	pub active_slot: Option<crate::behaviors::WeaponSlot>,
+	pub active_slots: std::collections::HashSet<String>,
+}
+
+impl ActiveWeaponState {
+	pub fn is_active(&self, slot: &crate::behaviors::WeaponSlot) -> bool {
+		self.active_slots.contains(&slot.0)
+	}
+}
+
+// Weapons without a slot are always active; slotted weapons follow their owner's active slots
+pub(crate) fn is_slot_active(
+	active_states: &Query<&ActiveWeaponState>,
+	owner: Option<&crate::behaviors::WeaponOwner>,
+	slot: Option<&crate::behaviors::WeaponSlot>,
+) -> bool {
+	slot.is_none_or(|slot| {
+		owner
+			.and_then(|owner| active_states.get(owner.0).ok())
+			.is_some_and(|state| state.is_active(slot))
+	})
 }
 
 #[derive(Component)]
@@ -92,27 +146,41 @@ impl Plugin for WeaponsPlugin {
 		app.init_asset::<WeaponData>()
 			.init_asset_loader::<WeaponDataLoader>()
 			.init_resource::<OrbitingEntityCount>()
-			.init_resource::<WeaponInventory>()
-			.init_resource::<ActiveWeaponState>()
 			.add_systems(
 				Update,
 				(
 					initialize_weapon_registry,
-					update_weapon_activation,
-					apply_weapon_upgrades,
-					sync_weapon_stats,
+					initialize_weapon_slots,
+					// Manual slots are only active for one frame, so consumers must see it
+					update_weapon_activation
+						.before(update_projectile_spawners)
+						.before(update_beam_emitters)
+						.before(detect_melee_targets),
 					update_following_entities,
 					redistribute_orbiting_entities,
 					update_orbiting_entities,
 					update_projectile_spawners,
+					update_boomerang_projectiles,
+					update_beam_emitters,
+					update_deployers,
+					update_summoners,
 					update_despawn_timers,
 					detect_melee_targets,
 					execute_melee_attack,
 					update_melee_hitboxes,
 					update_stunned_enemies,
+					finalize_weapon_evolutions.before(spawn_weapon_cooldown_bars),
 					spawn_weapon_cooldown_bars,
 					update_weapon_cooldown_bars,
 				),
+			)
+			.add_systems(
+				Update,
+				(
+					apply_weapon_upgrades,
+					apply_weapon_level_tables,
+					sync_weapon_stats,
+				),
 			);
 	}
 }
@@ -147,56 +215,20 @@ fn initialize_weapon_registry(
 	commands.insert_resource(WeaponRegistry { weapons });
 }
 
-fn update_weapon_activation(
-	keyboard: Res<ButtonInput<KeyCode>>,
-	gamepads: Query<&Gamepad>,
-	mut active_state: ResMut<ActiveWeaponState>,
-) {
-	use crate::behaviors::WeaponSlot;
-
-	// Check for button press events (not holds)
-	let melee_just_pressed = keyboard.just_pressed(KeyCode::KeyQ)
-		|| gamepads.iter().any(|g| g.just_pressed(GamepadButton::West));
-	let ranged_just_pressed = keyboard.just_pressed(KeyCode::KeyE)
-		|| gamepads.iter().any(|g| g.just_pressed(GamepadButton::East));
-
-	// Toggle melee weapon (takes priority if both pressed)
-	if melee_just_pressed {
-		active_state.active_slot = if active_state.active_slot == Some(WeaponSlot::Melee) {
-			None // Deactivate if already active
-		} else {
-			Some(WeaponSlot::Melee) // Activate melee
-		};
-	} else if ranged_just_pressed {
-		// Toggle ranged weapon only if melee wasn't pressed
-		active_state.active_slot = if active_state.active_slot == Some(WeaponSlot::Ranged) {
-			None // Deactivate if already active
-		} else {
-			Some(WeaponSlot::Ranged) // Activate ranged
-		};
-	}
-}
-
 // Generic spawn function that creates entities from weapon data
 pub fn spawn_entity_from_data(
 	commands: &mut Commands,
 	weapon_data: &WeaponData,
 	count: u32,
 	weapon_id: &str,
+	owner: Entity,
 ) -> Vec<Entity> {
 	use crate::behaviors::*;
 
 	let mut entities = Vec::new();
 
-	// Determine weapon slot based on behaviors
-	let weapon_slot = weapon_data
-		.behaviors
-		.iter()
-		.find_map(|behavior| match behavior {
-			BehaviorData::MeleeAttack { .. } => Some(WeaponSlot::Melee),
-			BehaviorData::ProjectileSpawner { .. } => Some(WeaponSlot::Ranged),
-			_ => None,
-		});
+	// Determine weapon slot from data or behaviors
+	let weapon_slot = weapon_data.slot_name().map(WeaponSlot);
 
 	for _ in 0..count {
 		let mut entity_commands = commands.spawn((
@@ -216,149 +248,281 @@ pub fn spawn_entity_from_data(
 			WeaponName(weapon_data.name.clone()),
 			WeaponId(weapon_id.to_string()),
 			WeaponLevel(1),
+			WeaponOwner(owner),
 		));
 
 		// Add weapon slot if applicable
-		if let Some(slot) = weapon_slot {
-			entity_commands.insert(slot);
+		if let Some(slot) = &weapon_slot {
+			entity_commands.insert(slot.clone());
 		}
 
 		// Add components based on behaviors
-		for behavior in &weapon_data.behaviors {
-			match behavior {
-				BehaviorData::Orbiting { radius, speed } => {
-					entity_commands.insert(OrbitingBehavior {
-						radius: *radius,
-						speed: *speed,
-						angle: 0.0, // Will be set by redistribution
-					});
-				}
-				BehaviorData::DamageOnContact {
-					damage,
-					damage_type,
-					targets,
-				} => {
-					entity_commands.insert((
-						DamageOnContact {
-							damage: *damage,
-							damage_type: *damage_type,
-							targets: *targets,
-						},
-						DamageStats { base: *damage },
-					));
-				}
-				BehaviorData::ProjectileSpawner {
-					cooldown,
-					damage,
-					speed,
-					lifetime,
-					projectile_size,
-					projectile_color,
-					spawn_logic,
-					fire_range,
-					energy_cost,
-				} => {
-					let mut timer = Timer::from_seconds(*cooldown, TimerMode::Repeating);
-					timer.tick(std::time::Duration::from_secs_f32(*cooldown)); // Start ready to fire
-					entity_commands.insert((
-						ProjectileSpawner {
-							cooldown: timer,
-							projectile_template: ProjectileTemplate {
-								damage: *damage,
-								speed: *speed,
-								lifetime: *lifetime,
-								size: *projectile_size,
-								color: *projectile_color,
-							},
-							spawn_logic: spawn_logic.clone(),
-							fire_range: *fire_range,
-							energy_cost: *energy_cost,
-						},
-						DamageStats { base: *damage },
-						CooldownStats { base: *cooldown },
-					));
-				}
-				BehaviorData::MeleeAttack {
-					cooldown,
-					detection_range,
-					damage,
-					stun_duration,
-					knockback_force,
-					attack_duration,
-					hitbox_size,
-					hitbox_color,
-					energy_cost,
-				} => {
-					let mut timer = Timer::from_seconds(*cooldown, TimerMode::Repeating);
-					timer.tick(std::time::Duration::from_secs_f32(*cooldown)); // Start ready to fire
-					entity_commands.insert((
-						MeleeAttack {
-							cooldown: timer,
-							detection_range: *detection_range,
-							damage: *damage,
-							stun_duration: *stun_duration,
-							knockback_force: *knockback_force,
-							attack_duration: *attack_duration,
-							hitbox_size: *hitbox_size,
-							hitbox_color: *hitbox_color,
-							energy_cost: *energy_cost,
-						},
-						DamageStats { base: *damage },
-						CooldownStats { base: *cooldown },
-						EffectStats {
-							base: *stun_duration,
-						},
-					));
-				}
-				BehaviorData::FollowPlayer => {
-					entity_commands.insert(FollowPlayer);
-				}
-				BehaviorData::SeekTarget { target_type, speed } => {
-					entity_commands.insert(SeekTarget {
-						target_type: *target_type,
-						speed: *speed,
-					});
-				}
-				BehaviorData::ZigZagMovement {
-					base_speed,
-					oscillation_speed,
-					oscillation_amplitude,
-				} => {
-					entity_commands.insert(ZigZagMovement {
-						base_speed: *base_speed,
-						oscillation_speed: *oscillation_speed,
-						oscillation_amplitude: *oscillation_amplitude,
-						time: 0.0,
-					});
-				}
-				BehaviorData::MaintainDistance {
-					target_type,
-					preferred_distance,
-					speed,
-				} => {
-					entity_commands.insert(MaintainDistance {
-						target_type: *target_type,
-						preferred_distance: *preferred_distance,
-						speed: *speed,
-					});
-				}
-				BehaviorData::ExplodeOnProximity { .. } => {
-					// ExplodeOnProximity is not used by weapons
-				}
-				BehaviorData::FlyingMovement => {
-					// FlyingMovement is not used by weapons
-				}
-			}
-		}
+		apply_weapon_behaviors(&mut entity_commands, &weapon_data.behaviors);
 
 		// Add UpgradeBehaviors from weapon data
 		if !weapon_data.upgrade_behaviors.is_empty() {
 			entity_commands.insert(UpgradeBehaviors(weapon_data.upgrade_behaviors.clone()));
 		}
 
+		// Add explicit level table from weapon data
+		if !weapon_data.levels.is_empty() {
+			entity_commands.insert(UpgradeLevels(weapon_data.levels.clone()));
+		}
+
 		let entity_id = entity_commands.id();
 		entities.push(entity_id);
 	}
 
 	entities
 }
+
+// Inserts the runtime components for each behavior onto a weapon-owned entity
+pub(crate) fn apply_weapon_behaviors(
+	entity_commands: &mut EntityCommands,
+	behaviors: &[BehaviorData],
+) {
+	use crate::behaviors::*;
+
+	for behavior in behaviors {
+		match behavior {
+			BehaviorData::Orbiting { radius, speed } => {
+				entity_commands.insert(OrbitingBehavior {
+					radius: *radius,
+					speed: *speed,
+					angle: 0.0, // Will be set by redistribution
+				});
+			}
+			BehaviorData::DamageOnContact {
+				damage,
+				damage_type,
+				targets,
+			} => {
+				entity_commands.insert((
+					DamageOnContact {
+						damage: *damage,
+						damage_type: *damage_type,
+						targets: *targets,
+					},
+					DamageStats { base: *damage },
+				));
+			}
+			BehaviorData::ProjectileSpawner {
+				cooldown,
+				damage,
+				speed,
+				lifetime,
+				projectile_size,
+				projectile_color,
+				spawn_logic,
+				fire_range,
+				energy_cost,
+				motion,
+				platform_collision,
+			} => {
+				let mut timer = Timer::from_seconds(*cooldown, TimerMode::Repeating);
+				timer.tick(std::time::Duration::from_secs_f32(*cooldown)); // Start ready to fire
+				entity_commands.insert((
+					ProjectileSpawner {
+						cooldown: timer,
+						projectile_template: ProjectileTemplate {
+							damage: *damage,
+							speed: *speed,
+							lifetime: *lifetime,
+							size: *projectile_size,
+							color: *projectile_color,
+							motion: *motion,
+							platform_collision: *platform_collision,
+						},
+						spawn_logic: spawn_logic.clone(),
+						fire_range: *fire_range,
+						energy_cost: *energy_cost,
+						spiral_angle: rand::random::<f32>() * 2.0 * std::f32::consts::PI,
+					},
+					DamageStats { base: *damage },
+					CooldownStats { base: *cooldown },
+				));
+			}
+			BehaviorData::Beam {
+				damage_per_second,
+				range,
+				width,
+				color,
+				energy_per_second,
+				piercing,
+			} => {
+				entity_commands.insert((
+					BeamEmitter {
+						damage_per_second: *damage_per_second,
+						range: *range,
+						width: *width,
+						color: *color,
+						energy_per_second: *energy_per_second,
+						piercing: *piercing,
+						visual: None,
+					},
+					DamageStats {
+						base: *damage_per_second,
+					},
+				));
+			}
+			BehaviorData::Deployable {
+				cooldown,
+				lifetime,
+				max_active,
+				size,
+				color,
+				energy_cost,
+				behaviors,
+			} => {
+				let mut timer = Timer::from_seconds(*cooldown, TimerMode::Repeating);
+				timer.tick(std::time::Duration::from_secs_f32(*cooldown)); // Start ready to deploy
+				entity_commands.insert((
+					Deployer {
+						cooldown: timer,
+						lifetime: *lifetime,
+						max_active: *max_active,
+						size: *size,
+						color: *color,
+						energy_cost: *energy_cost,
+						behaviors: behaviors.clone(),
+					},
+					CooldownStats { base: *cooldown },
+				));
+			}
+			BehaviorData::Summon {
+				cooldown,
+				max_summons,
+				health,
+				lifetime,
+				size,
+				color,
+				energy_cost,
+				behaviors,
+			} => {
+				// Minion contact damage scales through the summoner's DamageStats
+				let damage = behaviors
+					.iter()
+					.find_map(|behavior| match behavior {
+						BehaviorData::DamageOnContact { damage, .. } => Some(*damage),
+						_ => None,
+					})
+					.unwrap_or(0.0);
+
+				let mut timer = Timer::from_seconds(*cooldown, TimerMode::Repeating);
+				timer.tick(std::time::Duration::from_secs_f32(*cooldown)); // Start ready to summon
+				entity_commands.insert((
+					Summoner {
+						cooldown: timer,
+						max_summons: *max_summons,
+						health: *health,
+						lifetime: *lifetime,
+						size: *size,
+						color: *color,
+						energy_cost: *energy_cost,
+						damage,
+						behaviors: behaviors.clone(),
+					},
+					DamageStats { base: damage },
+					CooldownStats { base: *cooldown },
+					EffectStats {
+						base: *max_summons as f32,
+					},
+				));
+			}
+			BehaviorData::MeleeAttack {
+				cooldown,
+				detection_range,
+				damage,
+				stun_duration,
+				knockback_force,
+				attack_duration,
+				hitbox_size,
+				hitbox_color,
+				energy_cost,
+				combo,
+			} => {
+				let mut timer = Timer::from_seconds(*cooldown, TimerMode::Repeating);
+				timer.tick(std::time::Duration::from_secs_f32(*cooldown)); // Start ready to fire
+				entity_commands.insert((
+					MeleeAttack {
+						cooldown: timer,
+						detection_range: *detection_range,
+						damage: *damage,
+						stun_duration: *stun_duration,
+						knockback_force: *knockback_force,
+						attack_duration: *attack_duration,
+						hitbox_size: *hitbox_size,
+						hitbox_color: *hitbox_color,
+						energy_cost: *energy_cost,
+					},
+					DamageStats { base: *damage },
+					CooldownStats { base: *cooldown },
+					EffectStats {
+						base: *stun_duration,
+					},
+				));
+
+				if let Some(combo) = combo.as_ref().filter(|combo| !combo.hits.is_empty()) {
+					let mut window = Timer::from_seconds(combo.window, TimerMode::Once);
+					window.tick(std::time::Duration::from_secs_f32(combo.window)); // Start outside the window
+					entity_commands.insert(MeleeCombo {
+						hits: combo.hits.clone(),
+						window,
+						step: 0,
+					});
+				}
+			}
+			BehaviorData::FollowPlayer => {
+				entity_commands.insert(FollowPlayer);
+			}
+			BehaviorData::SeekTarget { target_type, speed } => {
+				entity_commands.insert(SeekTarget {
+					target_type: *target_type,
+					speed: *speed,
+				});
+			}
+			BehaviorData::ZigZagMovement {
+				base_speed,
+				oscillation_speed,
+				oscillation_amplitude,
+			} => {
+				entity_commands.insert(ZigZagMovement {
+					base_speed: *base_speed,
+					oscillation_speed: *oscillation_speed,
+					oscillation_amplitude: *oscillation_amplitude,
+					time: 0.0,
+				});
+			}
+			BehaviorData::MaintainDistance {
+				target_type,
+				preferred_distance,
+				speed,
+			} => {
+				entity_commands.insert(MaintainDistance {
+					target_type: *target_type,
+					preferred_distance: *preferred_distance,
+					speed: *speed,
+				});
+			}
+			BehaviorData::ExplodeOnProximity {
+				trigger_range,
+				damage,
+				targets,
+			} => {
+				// Used by deployed mines
+				entity_commands.insert((
+					ExplodeOnProximity {
+						trigger_range: *trigger_range,
+						damage: *damage,
+						targets: *targets,
+					},
+					DamageStats { base: *damage },
+				));
+			}
+			BehaviorData::FlyingMovement => {
+				// Used by flying summons
+				entity_commands.insert(FlyingMovement);
+			}
+		}
+	}
+}
@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+
+/// Weapon slots from game_config.ron, in HUD order.
+#[derive(Resource)]
+pub struct WeaponSlotLoadout {
+	pub slots: Vec<crate::WeaponSlotConfig>,
+}
+
+impl WeaponSlotLoadout {
+	pub fn get(&self, name: &str) -> Option<&crate::WeaponSlotConfig> {
+		self.slots.iter().find(|slot| slot.name == name)
+	}
+
+	// Each slot is driven by the Weapon input action at its position
+	pub fn action(&self, name: &str) -> Option<crate::input::InputAction> {
+		self.slots
+			.iter()
+			.position(|slot| slot.name == name)
+			.map(crate::input::InputAction::Weapon)
+	}
+}
+
+// Load the slot loadout once the game config is available
+pub fn initialize_weapon_slots(
+	mut commands: Commands,
+	loadout: Option<Res<WeaponSlotLoadout>>,
+	game_config: Option<Res<crate::GameConfig>>,
+	config_assets: Res<Assets<crate::GameConfigData>>,
+	mut bindings: ResMut<crate::input::InputBindings>,
+) {
+	use crate::SlotActivation;
+
+	// Only initialize once
+	if loadout.is_some() {
+		return;
+	}
+
+	let Some(config) = game_config else { return };
+	let Some(config_data) = config_assets.get(&config.config_handle) else {
+		return;
+	};
+
+	// Slot keys from the config are the defaults for the Weapon actions, unless rebound
+	for (index, slot) in config_data.weapon_slots.iter().enumerate() {
+		let action = crate::input::InputAction::Weapon(index);
+		if slot.activation == SlotActivation::AlwaysOn || bindings.get(action).is_some() {
+			continue;
+		}
+		bindings.set(crate::input::ActionBinding {
+			action,
+			keys: slot
+				.key
+				.as_deref()
+				.and_then(crate::input::parse_key_code)
+				.into_iter()
+				.collect(),
+			buttons: slot
+				.gamepad_button
+				.as_deref()
+				.and_then(crate::input::parse_gamepad_button)
+				.into_iter()
+				.collect(),
+		});
+	}
+
+	commands.insert_resource(WeaponSlotLoadout {
+		slots: config_data.weapon_slots.clone(),
+	});
+}
+
+// Update which weapon slots are active for each player based on each slot's activation mode
+pub fn update_weapon_activation(
+	input: crate::input::ActionInput,
+	loadout: Option<Res<WeaponSlotLoadout>>,
+	mut player_query: Query<(
+		&crate::input::PlayerController,
+		&mut super::ActiveWeaponState,
+	)>,
+) {
+	use crate::SlotActivation;
+
+	let Some(loadout) = loadout else { return };
+
+	for (controller, mut active_state) in player_query.iter_mut() {
+		for (index, slot) in loadout.slots.iter().enumerate() {
+			let action = crate::input::InputAction::Weapon(index);
+			let just_pressed = input.just_pressed(controller, action);
+			let pressed = input.pressed(controller, action);
+
+			let active = match slot.activation {
+				SlotActivation::AlwaysOn => true,
+				SlotActivation::Toggle => {
+					active_state.active_slots.contains(&slot.name) != just_pressed
+				}
+				SlotActivation::Hold => pressed,
+				SlotActivation::Manual => just_pressed, // Active for the frame it was triggered
+			};
+
+			if active {
+				active_state.active_slots.insert(slot.name.clone());
+			} else {
+				active_state.active_slots.remove(&slot.name);
+			}
+		}
+	}
+}
+
+/// Short binding label for the HUD, e.g. "Q/West" or "Auto".
+pub fn binding_label(
+	loadout: &WeaponSlotLoadout,
+	name: &str,
+	bindings: &crate::input::InputBindings,
+) -> String {
+	match (loadout.get(name), loadout.action(name)) {
+		(Some(slot), _) if slot.activation == crate::SlotActivation::AlwaysOn => "Auto".to_string(),
+		(_, Some(action)) => bindings.label(action),
+		_ => "Unbound".to_string(),
+	}
+}
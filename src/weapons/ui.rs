@@ -3,6 +3,7 @@ use bevy::prelude::*;
 #[derive(Component)]
 pub struct WeaponCooldownBar {
 	pub weapon_entity: Entity,
+	pub row: usize, // Vertical slot on screen, freed when the bar is despawned
 }
 
 #[derive(Component)]
@@ -39,22 +40,19 @@ type NewMeleeWeaponsQuery<'w, 's> = Query<
 	(Entity, &'static super::WeaponName),
 	(With<crate::behaviors::MeleeAttack>, Without<HasCooldownUI>),
 >;
+type NewBeamWeaponsQuery<'w, 's> = Query<
+	'w,
+	's,
+	(Entity, &'static super::WeaponName),
+	(With<crate::behaviors::BeamEmitter>, Without<HasCooldownUI>),
+>;
 
 pub fn spawn_weapon_cooldown_bars(
 	mut commands: Commands,
 	projectile_weapons: NewProjectileWeaponsQuery,
 	melee_weapons: NewMeleeWeaponsQuery,
-	existing_projectile_weapons: Query<
-		Entity,
-		(
-			With<crate::behaviors::ProjectileSpawner>,
-			With<HasCooldownUI>,
-		),
-	>,
-	existing_melee_weapons: Query<
-		Entity,
-		(With<crate::behaviors::MeleeAttack>, With<HasCooldownUI>),
-	>,
+	beam_weapons: NewBeamWeaponsQuery,
+	existing_bars: Query<&WeaponCooldownBar, With<WeaponCooldownBarBackground>>,
 ) {
 	const LAYOUT: BarLayout = BarLayout {
 		width: 200.0,
@@ -63,20 +61,29 @@ pub fn spawn_weapon_cooldown_bars(
 		spacing: 25.0,
 	};
 
-	// Start bar index after existing weapons
-	let mut bar_index =
-		existing_projectile_weapons.iter().count() + existing_melee_weapons.iter().count();
+	// New bars fill the first free rows, reusing those left by despawned weapons
+	let mut used_rows: Vec<usize> = existing_bars.iter().map(|bar| bar.row).collect();
+	let mut next_row = move || {
+		let row = (0..)
+			.find(|row| !used_rows.contains(row))
+			.unwrap_or_default();
+		used_rows.push(row);
+		row
+	};
 
 	// Spawn bars for projectile weapons
 	for (entity, weapon_name) in projectile_weapons.iter() {
-		spawn_cooldown_bar(&mut commands, entity, &weapon_name.0, bar_index, &LAYOUT);
-		bar_index += 1;
+		spawn_cooldown_bar(&mut commands, entity, &weapon_name.0, next_row(), &LAYOUT);
 	}
 
 	// Spawn bars for melee weapons
 	for (entity, weapon_name) in melee_weapons.iter() {
-		spawn_cooldown_bar(&mut commands, entity, &weapon_name.0, bar_index, &LAYOUT);
-		bar_index += 1;
+		spawn_cooldown_bar(&mut commands, entity, &weapon_name.0, next_row(), &LAYOUT);
+	}
+
+	// Spawn bars for beam weapons (no cooldown, the bar just shows the slot)
+	for (entity, weapon_name) in beam_weapons.iter() {
+		spawn_cooldown_bar(&mut commands, entity, &weapon_name.0, next_row(), &LAYOUT);
 	}
 }
 
@@ -84,10 +91,10 @@ fn spawn_cooldown_bar(
 	commands: &mut Commands,
 	weapon_entity: Entity,
 	weapon_name: &str,
-	index: usize,
+	row: usize,
 	layout: &BarLayout,
 ) {
-	let y_position = layout.start_y + (index as f32 * layout.spacing);
+	let y_position = layout.start_y + (row as f32 * layout.spacing);
 
 	// Mark weapon as having UI
 	commands.entity(weapon_entity).insert(HasCooldownUI);
@@ -104,7 +111,7 @@ fn spawn_cooldown_bar(
 		},
 		BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
 		ZIndex(10),
-		WeaponCooldownBar { weapon_entity },
+		WeaponCooldownBar { weapon_entity, row },
 		WeaponCooldownBarBackground,
 	));
 
@@ -120,7 +127,7 @@ fn spawn_cooldown_bar(
 		},
 		BackgroundColor(Color::srgb(0.3, 0.7, 0.3)),
 		ZIndex(11),
-		WeaponCooldownBar { weapon_entity },
+		WeaponCooldownBar { weapon_entity, row },
 		WeaponCooldownBarForeground,
 	));
 
@@ -139,19 +146,67 @@ fn spawn_cooldown_bar(
 			..default()
 		},
 		ZIndex(12),
-		WeaponCooldownBar { weapon_entity },
+		WeaponCooldownBar { weapon_entity, row },
 		WeaponCooldownText,
 	));
 }
 
+type CooldownBarForegroundQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		&'static WeaponCooldownBar,
+		&'static mut Node,
+		&'static mut BackgroundColor,
+	),
+	With<WeaponCooldownBarForeground>,
+>;
+
+type LabeledWeaponsQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		&'static super::WeaponName,
+		Option<&'static crate::behaviors::WeaponSlot>,
+		Option<&'static crate::behaviors::MeleeCombo>,
+		Option<&'static crate::behaviors::WeaponOwner>,
+	),
+>;
+
+#[allow(clippy::too_many_arguments)]
 pub fn update_weapon_cooldown_bars(
 	projectile_weapons: Query<(Entity, &crate::behaviors::ProjectileSpawner)>,
 	melee_weapons: Query<(Entity, &crate::behaviors::MeleeAttack)>,
-	mut bars: Query<(&WeaponCooldownBar, &mut Node), With<WeaponCooldownBarForeground>>,
+	beam_weapons: Query<(), With<crate::behaviors::BeamEmitter>>,
+	labeled_weapons: LabeledWeaponsQuery,
+	mut bars: CooldownBarForegroundQuery,
+	mut labels: Query<(&WeaponCooldownBar, &mut Text), With<WeaponCooldownText>>,
+	loadout: Option<Res<super::WeaponSlotLoadout>>,
+	owners: Query<(&super::ActiveWeaponState, &crate::input::PlayerController)>,
+	bindings: Res<crate::input::InputBindings>,
 ) {
 	const BAR_WIDTH: f32 = 200.0;
 
-	for (bar, mut node) in bars.iter_mut() {
+	for (bar, mut node, mut color) in bars.iter_mut() {
+		// Dim bars whose slot is switched off
+		let slot_active = labeled_weapons
+			.get(bar.weapon_entity)
+			.ok()
+			.and_then(|(_, slot, _, owner)| slot.zip(owner))
+			.is_none_or(|(slot, owner)| {
+				owners
+					.get(owner.0)
+					.is_ok_and(|(active_weapon, _)| active_weapon.is_active(slot))
+			});
+		let bar_color = if slot_active {
+			Color::srgb(0.3, 0.7, 0.3)
+		} else {
+			Color::srgb(0.35, 0.4, 0.35)
+		};
+		if color.0 != bar_color {
+			color.0 = bar_color;
+		}
+
 		// Check if it's a projectile weapon
 		if let Ok((_, spawner)) = projectile_weapons.get(bar.weapon_entity) {
 			// Full bar when ready, empty when just fired, fills as it cools down
@@ -173,6 +228,42 @@ pub fn update_weapon_cooldown_bars(
 				melee.cooldown.fraction()
 			};
 			node.width = Val::Px(BAR_WIDTH * readiness);
+			continue;
+		}
+
+		// Beams are always ready
+		if beam_weapons.contains(bar.weapon_entity) {
+			node.width = Val::Px(BAR_WIDTH);
+		}
+	}
+
+	// Label each bar with its slot and binding, plus the next combo hit for combo weapons
+	for (bar, mut text) in labels.iter_mut() {
+		let Ok((weapon_name, slot, combo, owner)) = labeled_weapons.get(bar.weapon_entity) else {
+			continue;
+		};
+
+		// Co-op players other than the first get a prefix so bars can be told apart
+		let mut label = match owner.and_then(|owner| owners.get(owner.0).ok()) {
+			Some((_, controller)) if controller.index > 0 => format!("P{} ", controller.index + 1),
+			_ => String::new(),
+		};
+		label.push_str(&match slot {
+			Some(slot) => {
+				let binding = loadout
+					.as_ref()
+					.map(|loadout| super::binding_label(loadout, &slot.0, &bindings))
+					.unwrap_or_else(|| "Unbound".to_string());
+				format!("{} ({}) - {}", slot.0, binding, weapon_name.0)
+			}
+			None => weapon_name.0.clone(),
+		});
+		if let Some(combo) = combo {
+			label.push_str(&format!(" [{}/{}]", combo.step + 1, combo.hits.len()));
+		}
+
+		if **text != label {
+			**text = label;
 		}
 	}
 }
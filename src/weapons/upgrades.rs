@@ -14,6 +14,10 @@ pub type UpgradedWeaponsQuery<'w, 's> = Query<
 		Option<&'static mut crate::behaviors::DamageOnContact>,
 		Option<&'static mut crate::behaviors::ProjectileSpawner>,
 		Option<&'static mut crate::behaviors::MeleeAttack>,
+		Option<&'static mut crate::behaviors::BeamEmitter>,
+		Option<&'static mut crate::behaviors::Deployer>,
+		Option<&'static mut crate::behaviors::Summoner>,
+		&'static crate::behaviors::WeaponOwner,
 	),
 	Changed<crate::behaviors::WeaponLevel>,
 >;
@@ -24,7 +28,7 @@ pub fn apply_weapon_upgrades(
 	mut upgraded_weapons: UpgradedWeaponsQuery,
 	weapon_registry: Option<Res<super::WeaponRegistry>>,
 	weapon_data_assets: Res<Assets<super::WeaponData>>,
-	weapon_inventory: Option<Res<super::WeaponInventory>>,
+	inventories: Query<&super::WeaponInventory>,
 ) {
 	for (
 		entity,
@@ -37,11 +41,16 @@ pub fn apply_weapon_upgrades(
 		mut damage_on_contact,
 		mut projectile,
 		mut melee,
+		mut beam,
+		mut deployer,
+		mut summoner,
+		owner,
 	) in upgraded_weapons.iter_mut()
 	{
-		// Check if this entity is the primary weapon in the inventory
-		let is_primary = weapon_inventory
-			.as_ref()
+		// Check if this entity is the primary weapon in its owner's inventory
+		let is_primary = inventories
+			.get(owner.0)
+			.ok()
 			.and_then(|inv| inv.weapons.get(&weapon_id.0))
 			.map(|(primary_entity, _)| *primary_entity == entity)
 			.unwrap_or(false);
@@ -66,6 +75,16 @@ pub fn apply_weapon_upgrades(
 						if let Some(ref mut mel) = melee {
 							mel.damage = new_damage;
 						}
+
+						// Apply to BeamEmitter if present (damage is per second)
+						if let Some(ref mut b) = beam {
+							b.damage_per_second = new_damage;
+						}
+
+						// Apply to Summoner if present (used by newly summoned minions)
+						if let Some(ref mut sum) = summoner {
+							sum.damage = new_damage;
+						}
 					}
 				}
 				crate::behaviors::UpgradeBehavior::ReduceCooldown {
@@ -84,6 +103,12 @@ pub fn apply_weapon_upgrades(
 						if let Some(ref mut mel) = melee {
 							mel.cooldown.set_duration(duration);
 						}
+						if let Some(ref mut dep) = deployer {
+							dep.cooldown.set_duration(duration);
+						}
+						if let Some(ref mut sum) = summoner {
+							sum.cooldown.set_duration(duration);
+						}
 					}
 				}
 				crate::behaviors::UpgradeBehavior::IncreaseEffect { per_level } => {
@@ -94,6 +119,9 @@ pub fn apply_weapon_upgrades(
 						if let Some(ref mut mel) = melee {
 							mel.stun_duration = new_effect;
 						}
+						if let Some(ref mut sum) = summoner {
+							sum.max_summons = new_effect.floor() as u32;
+						}
 					}
 				}
 				crate::behaviors::UpgradeBehavior::SpawnAdditionalEntity => {
@@ -104,54 +132,222 @@ pub fn apply_weapon_upgrades(
 					}
 
 					// Spawn one additional entity (e.g., for orbiting blades)
-					if let Some(registry) = &weapon_registry {
-						if let Some(weapon_handle) = registry.get(&weapon_id.0) {
-							if let Some(weapon_data) = weapon_data_assets.get(weapon_handle) {
-								let new_entities = super::spawn_entity_from_data(
-									&mut commands,
-									weapon_data,
-									1,
-									&weapon_id.0,
-								);
-
-								// Set new entities to the current weapon level
-								for new_entity in new_entities {
-									commands.entity(new_entity).insert(*weapon_level);
-								}
-							}
-						}
-					}
+					spawn_additional_instance(
+						&mut commands,
+						weapon_registry.as_deref(),
+						&weapon_data_assets,
+						&weapon_id.0,
+						*weapon_level,
+						owner.0,
+					);
 				}
 			}
 		}
 	}
 }
 
-// Sync damage across all entities with the same WeaponId (for weapons with multiple instances like orbiting blades)
+pub type LevelTableWeaponsQuery<'w, 's> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'static crate::behaviors::WeaponId,
+		&'static crate::behaviors::WeaponLevel,
+		&'static crate::behaviors::UpgradeLevels,
+		Option<&'static crate::behaviors::DamageStats>,
+		Option<&'static crate::behaviors::CooldownStats>,
+		Option<&'static crate::behaviors::EffectStats>,
+		Option<&'static mut crate::behaviors::DamageOnContact>,
+		Option<&'static mut crate::behaviors::ProjectileSpawner>,
+		Option<&'static mut crate::behaviors::MeleeAttack>,
+		Option<&'static mut crate::behaviors::BeamEmitter>,
+		Option<&'static mut crate::behaviors::Deployer>,
+		Option<&'static mut crate::behaviors::Summoner>,
+		&'static crate::behaviors::WeaponOwner,
+	),
+	Changed<crate::behaviors::WeaponLevel>,
+>;
+
+// Applies explicit per-level stat deltas from a weapon's level table
+pub fn apply_weapon_level_tables(
+	mut commands: Commands,
+	mut upgraded_weapons: LevelTableWeaponsQuery,
+	weapon_registry: Option<Res<super::WeaponRegistry>>,
+	weapon_data_assets: Res<Assets<super::WeaponData>>,
+	inventories: Query<&super::WeaponInventory>,
+) {
+	use crate::behaviors::LevelStatDelta;
+
+	for (
+		entity,
+		weapon_id,
+		weapon_level,
+		upgrade_levels,
+		damage_stats,
+		cooldown_stats,
+		effect_stats,
+		mut damage_on_contact,
+		mut projectile,
+		mut melee,
+		mut beam,
+		mut deployer,
+		mut summoner,
+		owner,
+	) in upgraded_weapons.iter_mut()
+	{
+		// Level 1 is the base weapon; each level after that unlocks one table entry
+		let unlocked_count =
+			(weapon_level.0.saturating_sub(1) as usize).min(upgrade_levels.0.len());
+		let unlocked = &upgrade_levels.0[..unlocked_count];
+
+		let mut damage_delta = 0.0;
+		let mut cooldown_delta = 0.0;
+		let mut effect_delta = 0.0;
+
+		for delta in unlocked.iter().flat_map(|level| &level.deltas) {
+			match delta {
+				LevelStatDelta::Damage(amount) => damage_delta += amount,
+				LevelStatDelta::Cooldown(amount) => cooldown_delta += amount,
+				LevelStatDelta::Effect(amount) => effect_delta += amount,
+				LevelStatDelta::AdditionalEntity => {}
+			}
+		}
+
+		if let Some(damage_stats) = damage_stats {
+			let new_damage = (damage_stats.base + damage_delta).max(0.0);
+
+			if let Some(ref mut contact) = damage_on_contact {
+				contact.damage = new_damage;
+			}
+			if let Some(ref mut proj) = projectile {
+				proj.projectile_template.damage = new_damage;
+			}
+			if let Some(ref mut mel) = melee {
+				mel.damage = new_damage;
+			}
+			if let Some(ref mut b) = beam {
+				b.damage_per_second = new_damage;
+			}
+			if let Some(ref mut sum) = summoner {
+				sum.damage = new_damage;
+			}
+		}
+
+		if let Some(cooldown_stats) = cooldown_stats {
+			let new_cooldown =
+				(cooldown_stats.base + cooldown_delta).max(crate::constants::WEAPON_MIN_COOLDOWN);
+			let duration = std::time::Duration::from_secs_f32(new_cooldown);
+
+			if let Some(ref mut proj) = projectile {
+				proj.cooldown.set_duration(duration);
+			}
+			if let Some(ref mut mel) = melee {
+				mel.cooldown.set_duration(duration);
+			}
+			if let Some(ref mut dep) = deployer {
+				dep.cooldown.set_duration(duration);
+			}
+			if let Some(ref mut sum) = summoner {
+				sum.cooldown.set_duration(duration);
+			}
+		}
+
+		if let Some(effect_stats) = effect_stats {
+			if let Some(ref mut mel) = melee {
+				mel.stun_duration = (effect_stats.base + effect_delta).max(0.0);
+			}
+			if let Some(ref mut sum) = summoner {
+				sum.max_summons = (effect_stats.base + effect_delta).max(0.0).floor() as u32;
+			}
+		}
+
+		// Additional instances come only from the level just reached, and only for the
+		// primary weapon in inventory so newly spawned instances don't cascade
+		let is_primary = inventories
+			.get(owner.0)
+			.ok()
+			.and_then(|inv| inv.weapons.get(&weapon_id.0))
+			.map(|(primary_entity, _)| *primary_entity == entity)
+			.unwrap_or(false);
+		let reached_new_entry = unlocked_count > 0 && weapon_level.0 as usize == unlocked_count + 1;
+
+		if is_primary && reached_new_entry {
+			let additional_count = unlocked[unlocked_count - 1]
+				.deltas
+				.iter()
+				.filter(|delta| matches!(delta, LevelStatDelta::AdditionalEntity))
+				.count();
+
+			for _ in 0..additional_count {
+				spawn_additional_instance(
+					&mut commands,
+					weapon_registry.as_deref(),
+					&weapon_data_assets,
+					&weapon_id.0,
+					*weapon_level,
+					owner.0,
+				);
+			}
+		}
+	}
+}
+
+// Spawns one more instance of a weapon at the given level
+fn spawn_additional_instance(
+	commands: &mut Commands,
+	weapon_registry: Option<&super::WeaponRegistry>,
+	weapon_data_assets: &Assets<super::WeaponData>,
+	weapon_id: &str,
+	weapon_level: crate::behaviors::WeaponLevel,
+	owner: Entity,
+) {
+	let Some(weapon_data) = weapon_registry
+		.and_then(|registry| registry.get(weapon_id))
+		.and_then(|handle| weapon_data_assets.get(handle))
+	else {
+		return;
+	};
+
+	let new_entities = super::spawn_entity_from_data(commands, weapon_data, 1, weapon_id, owner);
+
+	// Set new entities to the current weapon level
+	for new_entity in new_entities {
+		commands.entity(new_entity).insert(weapon_level);
+	}
+}
+
+// Sync damage across each player's entities with the same WeaponId (for weapons with multiple instances like orbiting blades)
 pub fn sync_weapon_stats(
-	mut weapon_entities: Query<(
-		&crate::behaviors::WeaponId,
-		&crate::behaviors::WeaponLevel,
-		&crate::behaviors::DamageStats,
-		&mut crate::behaviors::DamageOnContact,
-	)>,
+	mut weapon_entities: Query<
+		(
+			&crate::behaviors::WeaponOwner,
+			&crate::behaviors::WeaponId,
+			&crate::behaviors::WeaponLevel,
+			&crate::behaviors::DamageStats,
+			&mut crate::behaviors::DamageOnContact,
+		),
+		// Level-table weapons get their damage from apply_weapon_level_tables
+		Without<crate::behaviors::UpgradeLevels>,
+	>,
 ) {
 	use std::collections::HashMap;
 
-	// Find the highest level and damage for each weapon_id
-	let mut weapon_stats: HashMap<String, (u32, f32)> = HashMap::new();
+	// Find the highest level and damage for each player's weapon_id
+	let mut weapon_stats: HashMap<(Entity, String), (u32, f32)> = HashMap::new();
 
-	for (weapon_id, level, damage_stats, _) in weapon_entities.iter() {
-		let entry = weapon_stats.entry(weapon_id.0.clone()).or_insert((0, 0.0));
+	for (owner, weapon_id, level, damage_stats, _) in weapon_entities.iter() {
+		let entry = weapon_stats
+			.entry((owner.0, weapon_id.0.clone()))
+			.or_insert((0, 0.0));
 		if level.0 > entry.0 {
 			entry.0 = level.0;
 			entry.1 = damage_stats.base;
 		}
 	}
 
-	// Update all entities with the same weapon_id to have matching damage
-	for (weapon_id, _, damage_stats, mut contact) in weapon_entities.iter_mut() {
-		if let Some((max_level, _)) = weapon_stats.get(&weapon_id.0) {
+	// Update all of a player's entities with the same weapon_id to have matching damage
+	for (owner, weapon_id, _, damage_stats, mut contact) in weapon_entities.iter_mut() {
+		if let Some((max_level, _)) = weapon_stats.get(&(owner.0, weapon_id.0.clone())) {
 			// Recalculate damage based on max level
 			// This assumes ScaleDamage behavior - we could make this more sophisticated
 			let multiplier = 1.0